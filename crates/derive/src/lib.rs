@@ -1,7 +1,7 @@
 mod syntax;
 mod tuple;
 
-/// Derive `Syntax` trait for tuples (T,...)
+/// Derive `Syntax` and `ToSource` traits for tuples (T,...)
 #[proc_macro]
 pub fn derive_tuple_syntax(args: proc_macro::TokenStream) -> proc_macro::TokenStream {
     tuple::derive_tuple_syntax(args)
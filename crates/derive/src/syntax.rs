@@ -1,8 +1,9 @@
 use proc_macro::TokenStream;
 use quote::{ToTokens, format_ident, quote};
 use syn::{
-    Attribute, Error, Expr, Fields, Item, ItemEnum, ItemStruct, Lit, Result, Type, parse::Parser,
-    parse_macro_input, spanned::Spanned,
+    Attribute, Error, Expr, Fields, Generics, Ident, Item, ItemEnum, ItemStruct, Lit, LitInt,
+    Result, Type, TypeParamBound, WherePredicate, parse::Parser, parse_macro_input, parse_quote,
+    spanned::Spanned,
 };
 
 pub fn derive_syntax(input: TokenStream) -> TokenStream {
@@ -34,6 +35,9 @@ struct ItemConfig {
     take_while: Option<Expr>,
     c: Option<Lit>,
     semantic: Option<Expr>,
+    skip_trivia: Option<Expr>,
+    display: bool,
+    transparent: bool,
 }
 
 impl Default for ItemConfig {
@@ -45,12 +49,15 @@ impl Default for ItemConfig {
             take_while: None,
             c: None,
             semantic: None,
+            skip_trivia: None,
+            display: false,
+            transparent: false,
         }
     }
 }
 
 impl ItemConfig {
-    fn parse(attrs: &[Attribute]) -> Result<ItemConfig> {
+    fn parse(attrs: &[Attribute], generics: &Generics) -> Result<ItemConfig> {
         let met_lists = attrs
             .iter()
             .filter_map(|syntax| {
@@ -74,7 +81,10 @@ impl ItemConfig {
             .collect::<Result<Vec<_>>>()?;
 
         if met_lists.is_empty() {
-            return Ok(Default::default());
+            return Ok(ItemConfig {
+                ty_input: detect_input(generics),
+                ..Default::default()
+            });
         };
 
         let mut ty_input: Option<Type> = None;
@@ -83,6 +93,9 @@ impl ItemConfig {
         let mut c: Option<Lit> = None;
         let mut take_while: Option<Expr> = None;
         let mut semantic: Option<Expr> = None;
+        let mut skip_trivia: Option<Expr> = None;
+        let mut display = false;
+        let mut transparent = false;
 
         for meta_list in met_lists {
             let parser = syn::meta::parser(|meta| {
@@ -111,6 +124,10 @@ impl ItemConfig {
                         error!("The syntax has been set as a `take_while` or `char`.");
                     }
 
+                    if transparent {
+                        error!("The syntax has been set as `transparent`.");
+                    }
+
                     if keyword.is_some() {
                         error!("Call `keyword` twice.");
                     }
@@ -121,6 +138,10 @@ impl ItemConfig {
                         error!("The syntax has been set as a `keyword` or `char`.");
                     }
 
+                    if transparent {
+                        error!("The syntax has been set as `transparent`.");
+                    }
+
                     if take_while.is_some() {
                         error!("Call `take_while` twice.");
                     }
@@ -131,6 +152,10 @@ impl ItemConfig {
                         error!("The syntax has been set as a `keyword` or `take_while`.");
                     }
 
+                    if transparent {
+                        error!("The syntax has been set as `transparent`.");
+                    }
+
                     if c.is_some() {
                         error!("Call `char` twice.");
                     }
@@ -145,7 +170,45 @@ impl ItemConfig {
                         error!("Call `char` twice.");
                     }
 
+                    if transparent {
+                        error!("The syntax has been set as `transparent`.");
+                    }
+
                     semantic = Some(meta.value()?.parse()?);
+                } else if ident == "skip_trivia" {
+                    if keyword.is_some() || take_while.is_some() || c.is_some() {
+                        error!("The syntax has been set as a `keyword`, `take_while` or `char`.");
+                    }
+
+                    if transparent {
+                        error!("The syntax has been set as `transparent`.");
+                    }
+
+                    if skip_trivia.is_some() {
+                        error!("Call `skip_trivia` twice.");
+                    }
+
+                    skip_trivia = Some(meta.value()?.parse()?);
+                } else if ident == "display" {
+                    if display {
+                        error!("Call `display` twice.");
+                    }
+
+                    display = true;
+                } else if ident == "transparent" {
+                    if keyword.is_some() || take_while.is_some() || c.is_some() {
+                        error!("The syntax has been set as a `keyword`, `take_while` or `char`.");
+                    }
+
+                    if semantic.is_some() || skip_trivia.is_some() {
+                        error!("The syntax has been set with `semantic` or `skip_trivia`.");
+                    }
+
+                    if transparent {
+                        error!("Call `transparent` twice.");
+                    }
+
+                    transparent = true;
                 } else {
                     error!("Unsupport macro `syntax` option `{}`.", ident);
                 }
@@ -164,20 +227,77 @@ impl ItemConfig {
                 take_while,
                 c,
                 semantic,
+                skip_trivia,
+                display,
+                transparent,
             })
         } else {
             Ok(ItemConfig {
+                ty_input: detect_input(generics),
                 map_err,
                 keyword,
                 take_while,
                 c,
                 semantic,
-                ..Default::default()
+                skip_trivia,
+                display,
+                transparent,
             })
         }
     }
 }
 
+/// Finds the input type parameter when no `input = ...` option is given:
+/// the type parameter bounded by a trait whose name ends with `Input`,
+/// e.g. `I: CharsInput` or `where S: parserc::Input`, otherwise `I`.
+fn detect_input(generics: &Generics) -> Type {
+    fn is_input_bound<'a>(mut bounds: impl Iterator<Item = &'a TypeParamBound>) -> bool {
+        bounds.any(|bound| match bound {
+            TypeParamBound::Trait(bound) => bound
+                .path
+                .segments
+                .last()
+                .is_some_and(|segment| segment.ident.to_string().ends_with("Input")),
+            _ => false,
+        })
+    }
+
+    let from_params = generics
+        .type_params()
+        .find(|param| is_input_bound(param.bounds.iter()))
+        .map(|param| param.ident.clone());
+
+    let from_where_clause = || {
+        let type_params = generics
+            .type_params()
+            .map(|param| &param.ident)
+            .collect::<Vec<_>>();
+
+        generics
+            .where_clause
+            .iter()
+            .flat_map(|where_clause| where_clause.predicates.iter())
+            .find_map(|predicate| match predicate {
+                WherePredicate::Type(predicate) if is_input_bound(predicate.bounds.iter()) => {
+                    match &predicate.bounded_ty {
+                        Type::Path(path) => path
+                            .path
+                            .get_ident()
+                            .filter(|ident| type_params.contains(ident))
+                            .cloned(),
+                        _ => None,
+                    }
+                }
+                _ => None,
+            })
+    };
+
+    match from_params.or_else(from_where_clause) {
+        Some(ident) => syn::parse2(quote! { #ident }).unwrap(),
+        None => ItemConfig::default().ty_input,
+    }
+}
+
 #[derive(Default)]
 struct FieldConfig {
     crucial: bool,
@@ -186,7 +306,12 @@ struct FieldConfig {
     keyword: Option<Lit>,
     take_while: Option<Expr>,
     parser: Option<Expr>,
+    try_parser: Option<Expr>,
     semantic: Option<Expr>,
+    /// The inclusive `(min, max)` bounds of the `repeat` option.
+    repeat: Option<(usize, usize)>,
+    separated_by: Option<Type>,
+    trailing: bool,
 }
 
 impl FieldConfig {
@@ -223,7 +348,11 @@ impl FieldConfig {
         let mut keyword: Option<Lit> = None;
         let mut take_while: Option<Expr> = None;
         let mut parser: Option<Expr> = None;
+        let mut try_parser: Option<Expr> = None;
         let mut semantic: Option<Expr> = None;
+        let mut repeat: Option<(usize, usize)> = None;
+        let mut separated_by: Option<Type> = None;
+        let mut trailing = false;
 
         for meta_list in met_lists {
             let parser = syn::meta::parser(|meta| {
@@ -265,20 +394,88 @@ impl FieldConfig {
                     }
                     take_while = Some(meta.value()?.parse()?);
                 } else if ident == "parser" {
-                    if take_while.is_some() || keyword.is_some() {
-                        error!("The syntax has been set as a `keyword` or `take_while`.");
+                    if take_while.is_some() || keyword.is_some() || try_parser.is_some() {
+                        error!(
+                            "The syntax has been set as a `keyword`, `take_while` or `try_parser`."
+                        );
                     }
 
                     if parser.is_some() {
                         error!("Call `parser` twice.");
                     }
                     parser = Some(meta.value()?.parse()?);
+                } else if ident == "try_parser" {
+                    if take_while.is_some() || keyword.is_some() || parser.is_some() {
+                        error!("The syntax has been set as a `keyword`, `take_while` or `parser`.");
+                    }
+
+                    if try_parser.is_some() {
+                        error!("Call `try_parser` twice.");
+                    }
+                    try_parser = Some(meta.value()?.parse()?);
                 } else if ident == "semantic" {
                     if keyword.is_some() || take_while.is_some() {
                         error!("The syntax has been set as a `keyword` or `take_while`.");
                     }
 
                     semantic = Some(meta.value()?.parse()?);
+                } else if ident == "repeat" {
+                    if keyword.is_some()
+                        || take_while.is_some()
+                        || parser.is_some()
+                        || try_parser.is_some()
+                    {
+                        error!(
+                            "The syntax has been set as a `keyword`, `take_while`, `parser` or `try_parser`."
+                        );
+                    }
+
+                    if separated_by.is_some() {
+                        error!("The syntax has been set as `separated_by`.");
+                    }
+
+                    if repeat.is_some() {
+                        error!("Call `repeat` twice.");
+                    }
+
+                    let mut min = 0;
+                    let mut max = usize::MAX;
+
+                    meta.parse_nested_meta(|meta| {
+                        if meta.path.is_ident("min") {
+                            min = meta.value()?.parse::<LitInt>()?.base10_parse()?;
+                        } else if meta.path.is_ident("max") {
+                            max = meta.value()?.parse::<LitInt>()?.base10_parse()?;
+                        } else {
+                            return Err(meta.error("Expect `min = N` or `max = M`."));
+                        }
+
+                        Ok(())
+                    })?;
+
+                    if min > max {
+                        error!("The `repeat` option requires `min <= max`.");
+                    }
+
+                    repeat = Some((min, max));
+                } else if ident == "separated_by" {
+                    if keyword.is_some()
+                        || take_while.is_some()
+                        || parser.is_some()
+                        || try_parser.is_some()
+                        || repeat.is_some()
+                    {
+                        error!(
+                            "The syntax has been set as a `keyword`, `take_while`, `parser`, `try_parser` or `repeat`."
+                        );
+                    }
+
+                    if separated_by.is_some() {
+                        error!("Call `separated_by` twice.");
+                    }
+                    separated_by = Some(meta.value()?.parse()?);
+                } else if ident == "trailing" {
+                    trailing = true;
                 } else {
                     error!("Unsupport macro `parserc` option `{}`.", ident);
                 }
@@ -289,6 +486,18 @@ impl FieldConfig {
             parser.parse2(meta_list.tokens.to_token_stream())?;
         }
 
+        if trailing && separated_by.is_none() {
+            let attr = attrs
+                .iter()
+                .find(|attr| attr.path().is_ident("parserc"))
+                .unwrap();
+
+            return Err(Error::new(
+                attr.span(),
+                "`trailing` can only be used along with `separated_by`.",
+            ));
+        }
+
         Ok(FieldConfig {
             crucial,
             left_recursion,
@@ -296,11 +505,154 @@ impl FieldConfig {
             keyword,
             take_while,
             parser,
+            try_parser,
             semantic,
+            repeat,
+            separated_by,
+            trailing,
         })
     }
 }
 
+/// Returns the item type `T` of a `Vec<T>` field type required by the `option`.
+fn vec_item<'a>(ty: &'a Type, option: &str) -> Result<&'a Type> {
+    let item = match ty {
+        Type::Path(path) => path.path.segments.last().and_then(|segment| {
+            if segment.ident != "Vec" {
+                return None;
+            }
+
+            match &segment.arguments {
+                syn::PathArguments::AngleBracketed(args) if args.args.len() == 1 => {
+                    match args.args.first() {
+                        Some(syn::GenericArgument::Type(item)) => Some(item),
+                        _ => None,
+                    }
+                }
+                _ => None,
+            }
+        }),
+        _ => None,
+    };
+
+    item.ok_or_else(|| {
+        Error::new(
+            ty.span(),
+            format!(
+                "`{}` can only be applied to field with `Vec<_>` type.",
+                option
+            ),
+        )
+    })
+}
+
+/// Returns the parse expression of the `separated_by` option,
+/// parsing a `Punctuated` sequence and collecting its items into the `Vec<T>` field.
+fn separated_by_parse(
+    ty: &Type,
+    ty_input: &Type,
+    sep: &Type,
+    trailing: bool,
+) -> Result<proc_macro2::TokenStream> {
+    let item = vec_item(ty, "separated_by")?;
+
+    Ok(quote! {
+        <parserc::syntax::Punctuated<#item, #sep, #trailing> as parserc::syntax::Syntax<#ty_input>>::parse(input)
+            .map(|items| items.into_iter().collect::<#ty>())
+    })
+}
+
+/// Returns the bounded repetition parse expression of the `repeat` option,
+/// the field type must be `Vec<T>`.
+///
+/// Fails with a recoverable `Kind::Between` error if fewer than `min` items match,
+/// or if another item follows the `max`-th one.
+fn repeat_parse(
+    ty: &Type,
+    ty_input: &Type,
+    min: usize,
+    max: usize,
+) -> Result<proc_macro2::TokenStream> {
+    let item = vec_item(ty, "repeat")?;
+
+    Ok(quote! {
+        (|input: &mut #ty_input| {
+            let start = input.clone();
+            let items = parserc::between(#min, #max, || <#item as parserc::syntax::Syntax<#ty_input>>::parse).parse(input)?;
+
+            if items.len() == #max
+                && <#item as parserc::syntax::Syntax<#ty_input>>::parse
+                    .ok()
+                    .parse(&mut input.clone())?
+                    .is_some()
+            {
+                let span = start.to_span_at(start.len() - input.len());
+                *input = start;
+                return Err(parserc::Kind::Between(parserc::ControlFlow::Recovable, span).into());
+            }
+
+            Ok(items)
+        })
+        .parse(input)
+    })
+}
+
+/// Returns the field parse expression, unwrapped by `?`,
+/// prefixed by the trivia parser if it is not the first field.
+fn skip_trivia_before(
+    index: usize,
+    skip_trivia: &Option<Expr>,
+    parse: proc_macro2::TokenStream,
+) -> proc_macro2::TokenStream {
+    match skip_trivia {
+        Some(skip_trivia) if index > 0 => quote! {
+            {
+                (#skip_trivia).parse(input)?;
+                #parse?
+            }
+        },
+        _ => quote! { #parse? },
+    }
+}
+
+/// Implements `ToSource` and `Display` for the `#[parserc(display)]` option,
+/// requiring every field type to implement `ToSource`.
+fn derive_to_source<'a>(
+    ident: &Ident,
+    generics: &Generics,
+    field_types: impl IntoIterator<Item = &'a Type>,
+    body: proc_macro2::TokenStream,
+) -> proc_macro2::TokenStream {
+    let mut generics = generics.clone();
+
+    let where_clause = generics.make_where_clause();
+
+    for ty in field_types {
+        where_clause
+            .predicates
+            .push(parse_quote! { #ty: parserc::syntax::ToSource });
+    }
+
+    let (impl_generic, type_generic, where_clause) = generics.split_for_impl();
+
+    quote! {
+        impl #impl_generic parserc::syntax::ToSource for #ident #type_generic #where_clause {
+            #[inline]
+            fn to_source(&self, out: &mut parserc::__private::String) {
+                #body
+            }
+        }
+
+        impl #impl_generic core::fmt::Display for #ident #type_generic #where_clause {
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                let mut out = parserc::__private::String::new();
+                parserc::syntax::ToSource::to_source(self, &mut out);
+                f.write_str(&out)
+            }
+        }
+    }
+}
+
 fn derive_syntax_for_enum(item: ItemEnum) -> Result<proc_macro2::TokenStream> {
     let ItemConfig {
         ty_input,
@@ -309,7 +661,17 @@ fn derive_syntax_for_enum(item: ItemEnum) -> Result<proc_macro2::TokenStream> {
         take_while: token,
         c,
         semantic,
-    } = ItemConfig::parse(&item.attrs)?;
+        skip_trivia,
+        display,
+        transparent,
+    } = ItemConfig::parse(&item.attrs, &item.generics)?;
+
+    if transparent {
+        return Err(Error::new(
+            item.ident.span(),
+            "Deriving `transparent` from an enumeration is not supported.",
+        ));
+    }
 
     match (keyword, token, c) {
         (None, Some(param), None) => {
@@ -357,15 +719,20 @@ fn derive_syntax_for_enum(item: ItemEnum) -> Result<proc_macro2::TokenStream> {
             let parse_fields = varint
                 .fields
                 .iter()
-                .map(|field| {
+                .enumerate()
+                .map(|(index, field)| {
                     let FieldConfig {
                         crucial,
                         map_err,
                         keyword,
                         take_while: token,
                         parser,
+                        try_parser,
                         left_recursion,
                         semantic,
+                        repeat,
+                        separated_by,
+                        trailing,
                     } = FieldConfig::parse(&field.attrs)?;
 
                     let map_err = if let Some(map_err) = map_err {
@@ -406,6 +773,14 @@ fn derive_syntax_for_enum(item: ItemEnum) -> Result<proc_macro2::TokenStream> {
                         quote! {
                             #parser.parse(input)
                         }
+                    } else if let Some(try_parser) = try_parser {
+                        quote! {
+                            (#try_parser).or_default().parse(input)
+                        }
+                    } else if let Some((min, max)) = repeat {
+                        repeat_parse(&field.ty, &ty_input, min, max)?
+                    } else if let Some(sep) = separated_by {
+                        separated_by_parse(&field.ty, &ty_input, &sep, trailing)?
                     } else {
                         quote! {
                             input.parse()
@@ -440,11 +815,13 @@ fn derive_syntax_for_enum(item: ItemEnum) -> Result<proc_macro2::TokenStream> {
                         parse
                     };
 
+                    let parse = skip_trivia_before(index, &skip_trivia, parse);
+
                     let result = match &field.ident {
                         Some(ident) => Ok(quote! {
-                            #ident: #parse?
+                            #ident: #parse
                         }),
-                        None => Ok(quote! { #parse? }),
+                        None => Ok(quote! { #parse }),
                     };
 
                     if crucial {
@@ -540,7 +917,51 @@ fn derive_syntax_for_enum(item: ItemEnum) -> Result<proc_macro2::TokenStream> {
         .into_iter()
         .unzip();
 
+    let to_source = if display {
+        let arms = item.variants.iter().map(|variant| {
+            let variant_ident = &variant.ident;
+
+            let field_idents = variant
+                .fields
+                .members()
+                .map(|member| match member {
+                    syn::Member::Named(ident) => ident,
+                    syn::Member::Unnamed(index) => format_ident!("ident_{}", index),
+                })
+                .collect::<Vec<_>>();
+
+            let match_arm = if let Fields::Named(_) = &variant.fields {
+                quote! { Self::#variant_ident { #(#field_idents),* } }
+            } else {
+                quote! { Self::#variant_ident ( #(#field_idents),* ) }
+            };
+
+            quote! {
+                #match_arm => {
+                    #(parserc::syntax::ToSource::to_source(#field_idents, out);)*
+                }
+            }
+        });
+
+        derive_to_source(
+            ident,
+            &item.generics,
+            item.variants
+                .iter()
+                .flat_map(|variant| variant.fields.iter().map(|field| &field.ty)),
+            quote! {
+                match self {
+                    #(#arms),*
+                }
+            },
+        )
+    } else {
+        quote! {}
+    };
+
     Ok(quote! {
+        #to_source
+
         impl #impl_generic parserc::syntax::Syntax<#ty_input> for #ident #type_generic #where_clause {
             #[inline]
             fn parse(input: &mut #ty_input) -> Result<Self, <#ty_input as parserc::Input>::Error> {
@@ -572,10 +993,33 @@ fn derive_syntax_for_struct(item: ItemStruct) -> Result<proc_macro2::TokenStream
         take_while: token,
         c,
         semantic,
-    } = ItemConfig::parse(&item.attrs)?;
+        skip_trivia,
+        display,
+        transparent,
+    } = ItemConfig::parse(&item.attrs, &item.generics)?;
 
     let ident = &item.ident;
 
+    if transparent && item.fields.len() != 1 {
+        return Err(Error::new(
+            item.ident.span(),
+            "`transparent` can only be applied to a struct with exactly one field.",
+        ));
+    }
+
+    if transparent
+        && let Some(attr) = item
+            .fields
+            .iter()
+            .flat_map(|field| &field.attrs)
+            .find(|attr| attr.path().is_ident("parserc"))
+    {
+        return Err(Error::new(
+            attr.span(),
+            "The field of a `transparent` struct does not support `parserc` options.",
+        ));
+    }
+
     let map_err_global = if let Some(map_err) = map_err {
         quote! {
             .map_err(#map_err)
@@ -591,15 +1035,20 @@ fn derive_syntax_for_struct(item: ItemStruct) -> Result<proc_macro2::TokenStream
     let parse_fields = item
         .fields
         .iter()
-        .map(|field| {
+        .enumerate()
+        .map(|(index, field)| {
             let FieldConfig {
                 crucial,
                 map_err,
                 keyword,
                 take_while: token,
                 parser,
+                try_parser,
                 left_recursion,
                 semantic,
+                repeat,
+                separated_by,
+                trailing,
             } = FieldConfig::parse(&field.attrs)?;
 
             let map_err = if let Some(map_err) = map_err {
@@ -638,6 +1087,16 @@ fn derive_syntax_for_struct(item: ItemStruct) -> Result<proc_macro2::TokenStream
                 quote! {
                     #parser.parse(input)
                 }
+            } else if let Some(try_parser) = try_parser {
+                // A recoverable failure restores the input and yields `Default::default()`,
+                // i.e. `None` for an `Option<T>` field whose parser returns `Option<T>`.
+                quote! {
+                    (#try_parser).or_default().parse(input)
+                }
+            } else if let Some((min, max)) = repeat {
+                repeat_parse(&field.ty, &ty_input, min, max)?
+            } else if let Some(sep) = separated_by {
+                separated_by_parse(&field.ty, &ty_input, &sep, trailing)?
             } else {
                 quote! {
                     input.parse()
@@ -672,11 +1131,13 @@ fn derive_syntax_for_struct(item: ItemStruct) -> Result<proc_macro2::TokenStream
                 parse
             };
 
+            let parse = skip_trivia_before(index, &skip_trivia, parse);
+
             let result = match &field.ident {
                 Some(ident) => Ok(quote! {
-                    #ident: #parse?
+                    #ident: #parse
                 }),
-                None => Ok(quote! { #parse? }),
+                None => Ok(quote! { #parse }),
             };
 
             if crucial {
@@ -728,8 +1189,53 @@ fn derive_syntax_for_struct(item: ItemStruct) -> Result<proc_macro2::TokenStream
         }
     };
 
-    if let Some(keyword) = keyword {
-        Ok(quote! {
+    let to_source = if !display {
+        quote! {}
+    } else if keyword.is_some() || token.is_some() || c.is_some() {
+        derive_to_source(
+            ident,
+            &item.generics,
+            [&ty_input],
+            quote! {
+                parserc::syntax::ToSource::to_source(&self.0, out);
+            },
+        )
+    } else {
+        let members = item.fields.members();
+
+        derive_to_source(
+            ident,
+            &item.generics,
+            item.fields.iter().map(|field| &field.ty),
+            quote! {
+                #(parserc::syntax::ToSource::to_source(&self.#members, out);)*
+            },
+        )
+    };
+
+    let syntax = if transparent {
+        let field = item.fields.iter().next().unwrap();
+        let ty = &field.ty;
+        let member = item.fields.members().next().unwrap();
+
+        quote! {
+            impl #impl_generic parserc::syntax::Syntax<#ty_input> for #ident #type_generic #where_clause {
+                #[inline]
+                fn parse(input: &mut #ty_input) -> Result<Self, <#ty_input as parserc::Input>::Error> {
+                    use parserc::ParseError;
+
+                    <#ty as parserc::syntax::Syntax<#ty_input>>::parse(input)
+                        .map(|value| Self { #member: value })#map_err_global
+                }
+
+                #[inline]
+                fn to_span(&self) -> parserc::Span {
+                    parserc::syntax::Syntax::<#ty_input>::to_span(&self.#member)
+                }
+            }
+        }
+    } else if let Some(keyword) = keyword {
+        quote! {
             impl #impl_generic parserc::syntax::Syntax<#ty_input> for #ident #type_generic #where_clause {
                 #[inline]
                 fn parse(input: &mut #ty_input) -> Result<Self, <#ty_input as parserc::Input>::Error> {
@@ -742,9 +1248,9 @@ fn derive_syntax_for_struct(item: ItemStruct) -> Result<proc_macro2::TokenStream
                     self.0.to_span()
                 }
             }
-        })
+        }
     } else if let Some(token) = token {
-        Ok(quote! {
+        quote! {
             impl #impl_generic parserc::syntax::Syntax<#ty_input> for #ident #type_generic #where_clause {
                 #[inline]
                 fn parse(input: &mut #ty_input) -> Result<Self, <#ty_input as parserc::Input>::Error> {
@@ -757,9 +1263,9 @@ fn derive_syntax_for_struct(item: ItemStruct) -> Result<proc_macro2::TokenStream
                     self.0.to_span()
                 }
             }
-        })
+        }
     } else if let Some(c) = c {
-        Ok(quote! {
+        quote! {
             impl #impl_generic parserc::syntax::Syntax<#ty_input> for #ident #type_generic #where_clause {
                 #[inline]
                 fn parse(input: &mut #ty_input) -> Result<Self, <#ty_input as parserc::Input>::Error> {
@@ -772,9 +1278,9 @@ fn derive_syntax_for_struct(item: ItemStruct) -> Result<proc_macro2::TokenStream
                     self.0.to_span()
                 }
             }
-        })
+        }
     } else {
-        Ok(quote! {
+        quote! {
             impl #impl_generic parserc::syntax::Syntax<#ty_input> for #ident #type_generic #where_clause {
                 #[inline]
                 fn parse(input: &mut #ty_input) -> Result<Self, <#ty_input as parserc::Input>::Error> {
@@ -797,6 +1303,12 @@ fn derive_syntax_for_struct(item: ItemStruct) -> Result<proc_macro2::TokenStream
                     lhs
                 }
             }
-        })
-    }
+        }
+    };
+
+    Ok(quote! {
+        #to_source
+
+        #syntax
+    })
 }
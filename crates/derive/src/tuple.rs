@@ -46,7 +46,7 @@ pub fn derive_tuple_syntax(args: TokenStream) -> TokenStream {
                 #(#types: Syntax<I>),*
             {
                 #[inline]
-                fn parse(input: &mut I) -> std::result::Result<Self, I::Error> {
+                fn parse(input: &mut I) -> core::result::Result<Self, I::Error> {
                     #(
                         let #types = #types::parse(input)?;
                     )*
@@ -65,6 +65,18 @@ pub fn derive_tuple_syntax(args: TokenStream) -> TokenStream {
                     lhs
                 }
             }
+
+            impl<#(#types),*> ToSource for (#(#types),*)
+            where
+                #(#types: ToSource),*
+            {
+                #[inline]
+                fn to_source(&self, out: &mut String) {
+                    #(
+                        #pos.to_source(out);
+                    )*
+                }
+            }
         });
     }
 
@@ -0,0 +1,79 @@
+//! Build check of `parserc` on `#![no_std]` targets, see the `no-std` CI job.
+#![no_std]
+
+extern crate alloc;
+
+use parserc::{
+    Kind, Parser,
+    chars::{CharsInput, TokenStream},
+    dec_uint, next, sep_end_by0,
+    syntax::{Punctuated, Syntax, SyntaxInput},
+};
+
+/// A word token.
+#[derive(Debug, PartialEq, Syntax)]
+#[parserc(display, take_while = |c: char| c.is_ascii_alphabetic())]
+pub struct Word<I>(pub I)
+where
+    I: CharsInput;
+
+/// A `key=value` pair, printed back as its source.
+#[derive(Debug, PartialEq, Syntax)]
+#[parserc(display)]
+pub struct Pair<I>
+where
+    I: CharsInput,
+{
+    /// The key.
+    pub key: Word<I>,
+    /// The separator.
+    pub eq: parserc::syntax::Char<I, '='>,
+    /// The value.
+    pub value: Word<I>,
+}
+
+/// A word or a pair.
+#[derive(Debug, PartialEq, Syntax)]
+#[parserc(display)]
+pub enum Entry<I>
+where
+    I: CharsInput,
+{
+    Pair(Pair<I>),
+    Word(Word<I>),
+}
+
+/// A comma separated list of words, `Vec` is not in scope of the derived code.
+#[derive(Debug, PartialEq, Syntax)]
+pub struct WordList<I>
+where
+    I: CharsInput,
+{
+    /// The words.
+    #[parserc(separated_by = parserc::syntax::Char<I, ','>)]
+    pub words: alloc::vec::Vec<Word<I>>,
+    /// Two to four `;` terminators.
+    #[parserc(repeat(min = 2, max = 4))]
+    pub ends: alloc::vec::Vec<parserc::syntax::Char<I, ';'>>,
+}
+
+/// Parses a comma separated list of numbers.
+pub fn numbers(source: &str) -> Result<alloc::vec::Vec<u32>, Kind> {
+    sep_end_by0(dec_uint, || next(',')).parse(&mut TokenStream::from(source))
+}
+
+/// Parses a comma separated list of words.
+pub fn words(
+    source: &str,
+) -> Result<Punctuated<Word<TokenStream<'_>>, parserc::syntax::Char<TokenStream<'_>, ','>>, Kind> {
+    TokenStream::from(source).parse()
+}
+
+/// Parses an entry, returning its reconstructed source.
+pub fn entry(source: &str) -> Result<alloc::string::String, Kind> {
+    use alloc::string::ToString;
+
+    TokenStream::from(source)
+        .parse::<Entry<_>>()
+        .map(|entry| entry.to_string())
+}
@@ -1,14 +1,46 @@
 //! Parser combinators for tokenizer/lexer.
 
-use std::{fmt::Debug, ops::Range};
+use alloc::{string::String, vec, vec::Vec};
+use core::{fmt::Debug, ops::Range, str::FromStr};
 
 use crate::{
     Length, Span,
-    errors::{ControlFlow, Kind},
-    input::{Find, Input, Item, StartWith},
+    errors::{ControlFlow, Expected, Found, Kind},
+    input::{AsStr, Find, Input, Item, StartWith},
     parser::Parser,
 };
 
+#[inline]
+fn expected(what: Expected, found: Found, control_flow: ControlFlow, span: Span) -> Kind {
+    Kind::Expected {
+        what,
+        found,
+        span,
+        control_flow,
+    }
+}
+
+#[inline]
+fn expected_item<T>(item: T) -> Expected
+where
+    T: Item,
+{
+    item.to_char()
+        .map_or(Expected::Label("item"), Expected::Char)
+}
+
+#[inline]
+fn expected_keyword<KW>(keyword: &KW) -> Expected
+where
+    KW: Length,
+{
+    keyword
+        .as_text()
+        .map_or(Expected::Label("keyword"), |text| {
+            Expected::Keyword(String::from(text).into())
+        })
+}
+
 /// A parser match next item, otherwise raise an error.
 #[inline]
 pub fn next<I>(item: I::Item) -> impl Parser<I, Output = I>
@@ -16,14 +48,26 @@ where
     I: Input,
 {
     move |input: &mut I| {
-        if let Some(next) = input.iter().next() {
+        if let Some(next) = input.peek() {
             if next == item {
                 return Ok(input.split_to(item.len()));
             }
 
-            Err((Kind::Next(ControlFlow::Recovable, input.to_span_at(1))).into())
+            Err(expected(
+                expected_item(item),
+                Found::from(Some(next)),
+                ControlFlow::Recovable,
+                input.to_span_at(1),
+            )
+            .into())
         } else {
-            Err((Kind::Next(ControlFlow::Incomplete, input.to_span())).into())
+            Err(expected(
+                expected_item(item),
+                Found::Eof,
+                ControlFlow::Incomplete,
+                input.to_span(),
+            )
+            .into())
         }
     }
 }
@@ -36,14 +80,26 @@ where
     F: FnOnce(I::Item) -> bool,
 {
     move |input: &mut I| {
-        if let Some(next) = input.iter().next() {
+        if let Some(next) = input.peek() {
             if f(next) {
                 return Ok(input.split_to(next.len()));
             }
 
-            Err((Kind::NextIf(ControlFlow::Recovable, input.to_span_at(1))).into())
+            Err(expected(
+                Expected::Label("item matching the predicate"),
+                Found::from(Some(next)),
+                ControlFlow::Recovable,
+                input.to_span_at(1),
+            )
+            .into())
         } else {
-            Err((Kind::NextIf(ControlFlow::Incomplete, input.to_span_at(1))).into())
+            Err(expected(
+                Expected::Label("item matching the predicate"),
+                Found::Eof,
+                ControlFlow::Incomplete,
+                input.to_span_at(1),
+            )
+            .into())
         }
     }
 }
@@ -59,7 +115,69 @@ where
         if let Some(len) = input.starts_with(keyword.clone()) {
             Ok(input.split_to(len))
         } else {
-            Err((Kind::Keyword(ControlFlow::Recovable, input.to_span_at(keyword.len()))).into())
+            Err(expected(
+                expected_keyword(&keyword),
+                Found::from(input.peek()),
+                ControlFlow::Recovable,
+                input.to_span_at(keyword.len()),
+            )
+            .into())
+        }
+    }
+}
+
+/// Recogonize a keyword like [`keyword`], for inputs that implement [`Find`] but not [`StartWith`].
+///
+/// The keyword matches only if its first occurrence is at offset `0`. Unlike `starts_with`,
+/// a `find` that fails at the start keeps scanning the rest of the input, so a mismatch costs
+/// up to a full scan; prefer [`keyword`] when the input implements [`StartWith`].
+#[inline]
+pub fn keyword_find<KW, I>(keyword: KW) -> impl Parser<I, Output = I>
+where
+    I: Input + Find<KW>,
+    KW: Debug + Clone + Length,
+{
+    move |input: &mut I| {
+        if input.find(keyword.clone()) == Some(0) {
+            Ok(input.split_to(keyword.len()))
+        } else {
+            Err(expected(
+                expected_keyword(&keyword),
+                Found::from(input.peek()),
+                ControlFlow::Recovable,
+                input.to_span_at(keyword.len()),
+            )
+            .into())
+        }
+    }
+}
+
+/// Recogonize the longest keyword in `list` that prefixes the input,
+/// returns the matched slice and the index of the keyword in `list`.
+///
+/// Unlike trying the keywords in order, `..` does not shadow `..=` whatever their order in `list`.
+#[inline]
+pub fn keywords<I>(list: &'static [&'static str]) -> impl Parser<I, Output = (I, usize)>
+where
+    I: Input + StartWith<&'static str>,
+{
+    move |input: &mut I| {
+        let longest = list
+            .iter()
+            .enumerate()
+            .filter_map(|(index, keyword)| input.starts_with(keyword).map(|len| (len, index)))
+            .max_by(|(a, a_index), (b, b_index)| a.cmp(b).then(b_index.cmp(a_index)));
+
+        if let Some((len, index)) = longest {
+            Ok((input.split_to(len), index))
+        } else {
+            Err(expected(
+                Expected::OneOf(list),
+                Found::from(input.peek()),
+                ControlFlow::Recovable,
+                input.to_span_at(1),
+            )
+            .into())
         }
     }
 }
@@ -86,6 +204,43 @@ where
     }
 }
 
+/// Splits the whole input on the separator, returning the segments with the separators discarded.
+///
+/// Like [`str::split`], leading, trailing and adjacent separators produce empty segments,
+/// so `n` separators always yield `n + 1` segments, and an empty input yields one empty segment.
+///
+/// This parser will never returns an error.
+///
+/// # Panics
+///
+/// Panics if the separator matches an empty slice.
+#[inline]
+pub fn split_on<I, K>(sep: K) -> impl Parser<I, Output = Vec<I>>
+where
+    K: Debug + Clone,
+    I: Input + Find<K> + StartWith<K>,
+{
+    move |input: &mut I| {
+        let mut segments = vec![];
+
+        while let Some(offset) = input.find(sep.clone()) {
+            segments.push(input.split_to(offset));
+
+            let len = input
+                .starts_with(sep.clone())
+                .expect("separator found by `find`");
+
+            assert!(len > 0, "`split_on` with an empty separator {:?}", sep);
+
+            input.split_to(len);
+        }
+
+        segments.push(input.split_to(input.len()));
+
+        Ok(segments)
+    }
+}
+
 /// Returns the longest input slice (if any) that the predicate `F` returns true.
 ///
 /// This parser will never returns an error.
@@ -96,48 +251,60 @@ where
     F: FnMut(I::Item) -> bool,
 {
     move |input: &mut I| {
-        let mut iter = input.iter();
         let mut offset = 0;
-        loop {
-            if let Some(next) = iter.next() {
-                if !(cond)(next) {
-                    break;
-                }
-
-                offset += next.len();
-            } else {
+        for next in input.iter() {
+            if !(cond)(next) {
                 break;
             }
+
+            offset += next.len();
         }
 
         Ok(input.split_to(offset))
     }
 }
 
-/// Returns the longest input slice of length `n` (if any) that the predicate `F` returns true.
+/// Like [`take_while`], but for streaming input.
 ///
-/// This parser will never returns an error.
+/// If the predicate `F` still returns true for the last item, the match may be extended by more
+/// input, so this parser returns a [`ControlFlow::Incomplete`] error without consuming anything.
 #[inline]
-pub fn take_while_range_to<I, F>(n: usize, mut cond: F) -> impl Parser<I, Output = I>
+pub fn take_while_streaming<I, F>(mut cond: F) -> impl Parser<I, Output = I>
 where
     I: Input,
     F: FnMut(I::Item) -> bool,
 {
     move |input: &mut I| {
-        let mut iter = input.iter();
         let mut offset = 0;
-        let mut items = 0;
-        while let Some(next) = iter.next() {
+        for next in input.iter() {
             if !(cond)(next) {
-                break;
+                return Ok(input.split_to(offset));
             }
 
             offset += next.len();
-            items += 1;
+        }
+
+        Err(Kind::TakeWhile(ControlFlow::Incomplete, input.to_span()).into())
+    }
+}
 
-            if items + 1 == n {
+/// Returns the longest input slice of length `n` (if any) that the predicate `F` returns true.
+///
+/// This parser will never returns an error.
+#[inline]
+pub fn take_while_range_to<I, F>(n: usize, mut cond: F) -> impl Parser<I, Output = I>
+where
+    I: Input,
+    F: FnMut(I::Item) -> bool,
+{
+    move |input: &mut I| {
+        let mut offset = 0;
+        for (items, next) in input.iter().enumerate() {
+            if items + 1 >= n || !(cond)(next) {
                 break;
             }
+
+            offset += next.len();
         }
 
         Ok(input.split_to(offset))
@@ -154,10 +321,9 @@ where
     F: FnMut(I::Item) -> bool,
 {
     move |input: &mut I| {
-        let mut iter = input.iter();
         let mut items = 0;
         let mut offset = 0;
-        while let Some(next) = iter.next() {
+        for next in input.iter() {
             if !(cond)(next) {
                 break;
             }
@@ -188,21 +354,16 @@ where
     F: FnMut(I::Item) -> bool,
 {
     move |input: &mut I| {
-        let mut iter = input.iter();
         let mut items = 0;
         let mut offset = 0;
-        while let Some(next) = iter.next() {
-            if !(cond)(next) {
+        for next in input.iter() {
+            if items + 1 >= range.end || !(cond)(next) {
                 break;
             }
 
+            // `offset` counts bytes, `items` counts items, they differ for multi-byte chars.
             offset += next.len();
-
             items += 1;
-
-            if items + 1 == range.end {
-                break;
-            }
         }
 
         if items < range.start {
@@ -226,3 +387,725 @@ where
 {
     take_while(move |c: I::Item| !cond(c))
 }
+
+/// Parses the decimal number of `sign_len` bytes of sign followed by a run of ASCII digits.
+#[inline]
+fn dec_number<I, T>(input: &mut I, sign_len: usize) -> Result<T, I::Error>
+where
+    I: Input + AsStr,
+    T: FromStr,
+{
+    let digits = input
+        .iter()
+        .skip(sign_len)
+        .take_while(|c| c.to_char().is_some_and(|c| c.is_ascii_digit()))
+        .count();
+
+    if digits == 0 {
+        return Err(expected(
+            Expected::Label("decimal digit"),
+            Found::from(input.iter().nth(sign_len)),
+            ControlFlow::Recovable,
+            input.to_span_at(sign_len + 1),
+        )
+        .into());
+    }
+
+    let len = sign_len + digits;
+
+    let Ok(value) = input.as_str()[..len].parse() else {
+        return Err(Kind::Overflow(ControlFlow::Fatal, input.to_span_at(len)).into());
+    };
+
+    input.split_to(len);
+
+    Ok(value)
+}
+
+/// Parses a run of ASCII decimal digits into an unsigned number `T`.
+///
+/// An empty run is a recoverable [`Kind::Expected`] error, and a run out of range of `T`
+/// is a fatal [`Kind::Overflow`] error. The input is left unconsumed on errors.
+///
+/// ```
+/// use parserc::{ControlFlow, Kind, Parser, Span, chars::TokenStream, dec_uint};
+///
+/// assert_eq!(dec_uint::<_, u8>().parse(&mut TokenStream::<Kind>::from("42")), Ok(42));
+/// assert_eq!(
+///     dec_uint::<_, u8>().parse(&mut TokenStream::<Kind>::from("256")),
+///     Err(Kind::Overflow(ControlFlow::Fatal, Span::Range(0..3)))
+/// );
+/// ```
+#[inline]
+pub fn dec_uint<I, T>() -> impl Parser<I, Output = T>
+where
+    I: Input + AsStr,
+    T: FromStr,
+{
+    move |input: &mut I| dec_number(input, 0)
+}
+
+/// Parses an optional `+`/`-` sign followed by a run of ASCII decimal digits into a signed number `T`.
+///
+/// Errors are reported the same way as [`dec_uint`].
+#[inline]
+pub fn dec_int<I, T>() -> impl Parser<I, Output = T>
+where
+    I: Input + AsStr,
+    T: FromStr,
+{
+    move |input: &mut I| {
+        let sign_len = match input.peek().and_then(|item| item.to_char()) {
+            Some('+' | '-') => 1,
+            _ => 0,
+        };
+
+        dec_number(input, sign_len)
+    }
+}
+
+/// Repeatedly runs the parser created by `f` until it returns a `non-fatal` error,
+/// returning the number of matches and discarding the outputs.
+///
+/// ```
+/// use parserc::{Kind, Parser, chars::TokenStream, count, next};
+///
+/// let mut input = TokenStream::<Kind>::from("###title");
+///
+/// assert_eq!(count(|| next('#')).parse(&mut input), Ok(3));
+/// assert_eq!(input, TokenStream::from((3, "title")));
+/// ```
+#[inline]
+pub fn count<I, F, P>(mut f: F) -> impl Parser<I, Output = usize>
+where
+    I: Input + Clone,
+    F: FnMut() -> P,
+    P: Parser<I>,
+{
+    move |input: &mut I| {
+        let mut count = 0;
+
+        while f().ensure_progress().ok().parse(input)?.is_some() {
+            count += 1;
+        }
+
+        Ok(count)
+    }
+}
+
+/// Repeatedly runs the parser created by `f` until it returns a `non-fatal` error,
+/// appending the outputs into `buf` and returning the number of matches.
+///
+/// Unlike parsing a `Vec<T>`, this reuses the caller's collection, avoiding an allocation per parse.
+#[inline]
+pub fn collect_into<I, F, P, C>(buf: &mut C, mut f: F) -> impl Parser<I, Output = usize>
+where
+    I: Input + Clone,
+    F: FnMut() -> P,
+    P: Parser<I>,
+    C: Extend<P::Output>,
+{
+    move |input: &mut I| {
+        let mut count = 0;
+
+        while let Some(output) = f().ensure_progress().ok().parse(input)? {
+            buf.extend(Some(output));
+            count += 1;
+        }
+
+        Ok(count)
+    }
+}
+
+/// An output that can be appended to a [`String`], see [`collect_string`].
+pub trait PushStr {
+    /// Appends `self` to `out`.
+    fn push_to(&self, out: &mut String);
+}
+
+impl PushStr for char {
+    #[inline]
+    fn push_to(&self, out: &mut String) {
+        out.push(*self);
+    }
+}
+
+impl<T> PushStr for T
+where
+    T: AsStr,
+{
+    #[inline]
+    fn push_to(&self, out: &mut String) {
+        out.push_str(self.as_str());
+    }
+}
+
+/// Repeatedly runs the parser created by `f` until it returns a `non-fatal` error,
+/// appending the `char` or input slice outputs into a new [`String`].
+///
+/// ```
+/// use parserc::{Kind, Parser, chars::TokenStream, collect_string, next_if};
+///
+/// let mut input = TokenStream::<Kind>::from("abc1");
+///
+/// let word = collect_string(|| next_if(|c: char| c.is_ascii_alphabetic())).parse(&mut input);
+///
+/// assert_eq!(word.as_deref(), Ok("abc"));
+/// assert_eq!(input, TokenStream::from((3, "1")));
+/// ```
+#[inline]
+pub fn collect_string<I, F, P>(mut f: F) -> impl Parser<I, Output = String>
+where
+    I: Input + Clone,
+    F: FnMut() -> P,
+    P: Parser<I>,
+    P::Output: PushStr,
+{
+    move |input: &mut I| {
+        let mut out = String::new();
+
+        while let Some(output) = f().ensure_progress().ok().parse(input)? {
+            output.push_to(&mut out);
+        }
+
+        Ok(out)
+    }
+}
+
+/// Parses `operand (op operand)*` and folds the results from left to right with `combine`,
+/// e.g. `1-2-3` is folded as `combine(combine(1, -, 2), -, 3)`.
+///
+/// If an `op` is not followed by an operand, both are left unconsumed.
+#[inline]
+pub fn fold_left<I, FO, O, FP, P, F>(
+    mut operand: FO,
+    mut op: FP,
+    mut combine: F,
+) -> impl Parser<I, Output = O::Output>
+where
+    I: Input + Clone,
+    FO: FnMut() -> O,
+    O: Parser<I>,
+    FP: FnMut() -> P,
+    P: Parser<I>,
+    F: FnMut(O::Output, P::Output, O::Output) -> O::Output,
+{
+    move |input: &mut I| {
+        let mut acc = operand().parse(input)?;
+
+        loop {
+            let rhs = (|input: &mut I| {
+                let op = op().parse(input)?;
+                let rhs = operand().parse(input)?;
+                Ok((op, rhs))
+            })
+            .ensure_progress()
+            .ok()
+            .parse(input)?;
+
+            let Some((op, rhs)) = rhs else {
+                return Ok(acc);
+            };
+
+            acc = combine(acc, op, rhs);
+        }
+    }
+}
+
+/// Parses `element (separator element)*`, returning only the spans of the elements,
+/// e.g. the segment spans of `a::b::c` for highlighting, without building the elements.
+///
+/// If a `separator` is not followed by an element, both are left unconsumed.
+///
+/// ```
+/// use parserc::{Kind, Parser, Span, chars::TokenStream, collect_separated_spans, keyword, next};
+///
+/// let spans = collect_separated_spans(|| next('a').or(next('b')), || keyword("::"))
+///     .parse(&mut TokenStream::<Kind>::from("a::b::a"));
+///
+/// assert_eq!(spans, Ok(vec![Span::Range(0..1), Span::Range(3..4), Span::Range(6..7)]));
+/// ```
+#[inline]
+pub fn collect_separated_spans<I, FE, E, FS, S>(
+    mut element: FE,
+    mut separator: FS,
+) -> impl Parser<I, Output = Vec<Span>>
+where
+    I: Input + Clone,
+    FE: FnMut() -> E,
+    E: Parser<I>,
+    FS: FnMut() -> S,
+    S: Parser<I>,
+{
+    move |input: &mut I| {
+        let start = input.start();
+        element().parse(input)?;
+
+        let mut spans = vec![Span::Range(start..input.start())];
+
+        while let Some(span) = (|input: &mut I| {
+            separator().parse(input)?;
+            let start = input.start();
+            element().parse(input)?;
+            Ok(Span::Range(start..input.start()))
+        })
+        .ensure_progress()
+        .ok()
+        .parse(input)?
+        {
+            spans.push(span);
+        }
+
+        Ok(spans)
+    }
+}
+
+/// Parses zero or more `element separator` pairs, returning the elements.
+///
+/// Every element must be followed by a separator: if an element parses but its separator is missing,
+/// the element is left unconsumed and the parser stops with the elements collected so far,
+/// e.g. `a;b` yields `[a]` and leaves `b` in the input.
+#[inline]
+pub fn sep_end_by0<I, FE, E, FS, S>(
+    mut element: FE,
+    mut separator: FS,
+) -> impl Parser<I, Output = Vec<E::Output>>
+where
+    I: Input + Clone,
+    FE: FnMut() -> E,
+    E: Parser<I>,
+    FS: FnMut() -> S,
+    S: Parser<I>,
+{
+    move |input: &mut I| {
+        let mut elements = vec![];
+
+        while let Some(output) = (|input: &mut I| {
+            let output = element().parse(input)?;
+            separator().parse(input)?;
+            Ok(output)
+        })
+        .ensure_progress()
+        .ok()
+        .parse(input)?
+        {
+            elements.push(output);
+        }
+
+        Ok(elements)
+    }
+}
+
+/// Like [`sep_end_by0`], but requires at least one `element separator` pair.
+///
+/// If the first pair does not match, the error from its element or separator is returned.
+#[inline]
+pub fn sep_end_by1<I, FE, E, FS, S>(
+    mut element: FE,
+    mut separator: FS,
+) -> impl Parser<I, Output = Vec<E::Output>>
+where
+    I: Input + Clone,
+    FE: FnMut() -> E,
+    E: Parser<I>,
+    FS: FnMut() -> S,
+    S: Parser<I>,
+{
+    move |input: &mut I| {
+        let mut try_input = input.clone();
+        let first = element().parse(&mut try_input)?;
+        separator().parse(&mut try_input)?;
+        *input = try_input;
+
+        let mut elements = vec![first];
+        elements.extend(sep_end_by0(&mut element, &mut separator).parse(input)?);
+
+        Ok(elements)
+    }
+}
+
+/// Optionally parses `prefix`, then parses `body`, returning both outputs.
+///
+/// ```
+/// use parserc::{Kind, Parser, chars::TokenStream, keyword, opt_preceded, take_while};
+///
+/// let (sep, path) = opt_preceded(keyword("::"), take_while(|c: char| c.is_alphabetic()))
+///     .parse(&mut TokenStream::<Kind>::from("::path"))
+///     .unwrap();
+///
+/// assert_eq!(sep, Some(TokenStream::from("::")));
+/// assert_eq!(path, TokenStream::from((2, "path")));
+/// ```
+#[inline]
+pub fn opt_preceded<I, P1, P2>(
+    prefix: P1,
+    body: P2,
+) -> impl Parser<I, Output = (Option<P1::Output>, P2::Output)>
+where
+    I: Input + Clone,
+    P1: Parser<I>,
+    P2: Parser<I>,
+{
+    move |input: &mut I| {
+        let prefix = prefix.ok().parse(input)?;
+        let body = body.parse(input)?;
+
+        Ok((prefix, body))
+    }
+}
+
+/// Parses `first`, `sep`, then `second`, returning the outputs of `first` and `second`
+/// and discarding the separator, e.g. `key=value`.
+///
+/// ```
+/// use parserc::{Kind, Parser, chars::TokenStream, next, separated_pair, take_while};
+///
+/// let (key, value) = separated_pair(
+///     take_while(|c: char| c.is_alphabetic()),
+///     next('='),
+///     take_while(|c: char| c.is_alphabetic()),
+/// )
+/// .parse(&mut TokenStream::<Kind>::from("k=v"))
+/// .unwrap();
+///
+/// assert_eq!(key, TokenStream::from("k"));
+/// assert_eq!(value, TokenStream::from((2, "v")));
+/// ```
+#[inline]
+pub fn separated_pair<I, A, Sep, B>(
+    first: A,
+    sep: Sep,
+    second: B,
+) -> impl Parser<I, Output = (A::Output, B::Output)>
+where
+    I: Input,
+    A: Parser<I>,
+    Sep: Parser<I>,
+    B: Parser<I>,
+{
+    move |input: &mut I| {
+        let first = first.parse(input)?;
+        sep.parse(input)?;
+        let second = second.parse(input)?;
+
+        Ok((first, second))
+    }
+}
+
+/// Runs `parser` only if `enabled` is true, returning its output in `Some`,
+/// otherwise returns `None` without touching the input.
+///
+/// Errors of `parser` are returned unchanged, use [`ok`](Parser::ok) to make it optional as well.
+///
+/// ```
+/// use parserc::{Kind, Parser, chars::TokenStream, cond, next};
+///
+/// let mut input = TokenStream::<Kind>::from("#");
+///
+/// assert_eq!(cond(false, next('#')).parse(&mut input), Ok(None));
+/// assert_eq!(cond(true, next('#')).parse(&mut input), Ok(Some(TokenStream::from("#"))));
+/// ```
+#[inline]
+pub fn cond<I, P>(enabled: bool, parser: P) -> impl Parser<I, Output = Option<P::Output>>
+where
+    I: Input,
+    P: Parser<I>,
+{
+    move |input: &mut I| {
+        if !enabled {
+            return Ok(None);
+        }
+
+        parser.parse(input).map(Some)
+    }
+}
+
+/// Skips the leading whitespace, then runs `parser`, returning the span of the skipped whitespace
+/// with the output, or [`Span::None`] if there is no whitespace.
+///
+/// If `parser` fails, the whitespace is left unconsumed.
+///
+/// ```
+/// use parserc::{Kind, Parser, Span, chars::TokenStream, keyword, with_leading_ws};
+///
+/// assert_eq!(
+///     with_leading_ws(keyword("token")).parse(&mut TokenStream::<Kind>::from("   token")),
+///     Ok((Span::Range(0..3), TokenStream::from((3, "token"))))
+/// );
+/// ```
+#[inline]
+pub fn with_leading_ws<I, P>(parser: P) -> impl Parser<I, Output = (Span, P::Output)>
+where
+    I: Input + Clone,
+    P: Parser<I>,
+{
+    move |input: &mut I| {
+        let start = input.clone();
+
+        let ws = take_while(|item: I::Item| item.to_char().is_some_and(char::is_whitespace))
+            .parse(input)?;
+
+        let span = if ws.is_empty() {
+            Span::None
+        } else {
+            ws.to_span()
+        };
+
+        match parser.parse(input) {
+            Ok(output) => Ok((span, output)),
+            Err(err) => {
+                *input = start;
+                Err(err)
+            }
+        }
+    }
+}
+
+/// Parses `open`, a list of elements separated by separators with an optional trailing separator,
+/// then `close`, the combinator form of `Delimiter<Start, End, Punctuated<T, P>>`.
+///
+/// Returns the outputs of `open`, the elements and `close`. Once `open` matches,
+/// a missing `close` is a [`fatal`](ControlFlow::Fatal) error, reporting the unclosed delimiter.
+///
+/// ```
+/// use parserc::{Kind, Parser, chars::TokenStream, delimited_list, next, next_if};
+///
+/// let (_, elements, _) = delimited_list(
+///     next('('),
+///     || next_if(|c: char| c.is_ascii_digit()),
+///     || next(','),
+///     next(')'),
+/// )
+/// .parse(&mut TokenStream::<Kind>::from("(1,2,)"))
+/// .unwrap();
+///
+/// assert_eq!(elements.len(), 2);
+/// ```
+#[inline]
+pub fn delimited_list<I, O, FE, E, FS, S, C>(
+    open: O,
+    mut element: FE,
+    mut separator: FS,
+    close: C,
+) -> impl Parser<I, Output = (O::Output, Vec<E::Output>, C::Output)>
+where
+    I: Input + Clone,
+    O: Parser<I>,
+    FE: FnMut() -> E,
+    E: Parser<I>,
+    FS: FnMut() -> S,
+    S: Parser<I>,
+    C: Parser<I>,
+{
+    move |input: &mut I| {
+        let open = open.parse(input)?;
+
+        let mut elements = vec![];
+
+        loop {
+            let start = input.start();
+
+            let Some(output) = element().ok().parse(input)? else {
+                break;
+            };
+
+            elements.push(output);
+
+            if separator().ok().parse(input)?.is_none() {
+                break;
+            }
+
+            if input.start() == start {
+                return Err(Kind::NoProgress(ControlFlow::Fatal, input.to_span_at(0)).into());
+            }
+        }
+
+        let close = close.fatal().parse(input)?;
+
+        Ok((open, elements, close))
+    }
+}
+
+/// A tuple of parser factories that can be matched in any order, see [`permutation`].
+pub trait Permutation<I>
+where
+    I: Input,
+{
+    /// Tuple of the outputs, in the order of the factories.
+    type Output;
+
+    /// Matches every parser exactly once, in any order.
+    fn permutation(&mut self, input: &mut I) -> Result<Self::Output, I::Error>;
+}
+
+macro_rules! impl_permutation {
+    ($($f: ident $p: ident $idx: tt),+) => {
+        impl<I, $($f, $p),+> Permutation<I> for ($($f,)+)
+        where
+            I: Input + Clone,
+            $($f: FnMut() -> $p, $p: Parser<I>,)+
+        {
+            type Output = ($($p::Output,)+);
+
+            fn permutation(&mut self, input: &mut I) -> Result<Self::Output, I::Error> {
+                let start = input.clone();
+                let mut outputs = ($(Option::<$p::Output>::None,)+);
+
+                while $(outputs.$idx.is_none())||+ {
+                    $(
+                        if outputs.$idx.is_none()
+                            && let Some(output) = (self.$idx)().ok().parse(input)?
+                        {
+                            outputs.$idx = Some(output);
+                            continue;
+                        }
+                    )+
+
+                    let span = input.to_span_at(1);
+                    *input = start;
+
+                    return Err(Kind::Permutation(ControlFlow::Recovable, span).into());
+                }
+
+                Ok(($(outputs.$idx.unwrap(),)+))
+            }
+        }
+    };
+}
+
+impl_permutation!(F0 P0 0, F1 P1 1);
+impl_permutation!(F0 P0 0, F1 P1 1, F2 P2 2);
+impl_permutation!(F0 P0 0, F1 P1 1, F2 P2 2, F3 P3 3);
+impl_permutation!(F0 P0 0, F1 P1 1, F2 P2 2, F3 P3 3, F4 P4 4);
+impl_permutation!(F0 P0 0, F1 P1 1, F2 P2 2, F3 P3 3, F4 P4 4, F5 P5 5);
+impl_permutation!(F0 P0 0, F1 P1 1, F2 P2 2, F3 P3 3, F4 P4 4, F5 P5 5, F6 P6 6);
+impl_permutation!(F0 P0 0, F1 P1 1, F2 P2 2, F3 P3 3, F4 P4 4, F5 P5 5, F6 P6 6, F7 P7 7);
+
+/// Matches each parser created by the tuple of factories exactly once, in any order,
+/// returning the outputs in the order of the factories.
+///
+/// At each position the remaining parsers are tried in order. If none of them matches,
+/// e.g. a parser is missing or one already matched appears again, the input is restored
+/// and a recoverable [`Kind::Permutation`] error is returned.
+#[inline]
+pub fn permutation<I, L>(mut list: L) -> impl Parser<I, Output = L::Output>
+where
+    I: Input,
+    L: Permutation<I>,
+{
+    move |input: &mut I| list.permutation(input)
+}
+
+/// Runs the parser created by `f` between `min` and `max` times inclusive, returning the outputs.
+///
+/// Stops greedily after `max` matches or at the first `non-fatal` error. If fewer than `min`
+/// matched, the input is restored and a recoverable [`Kind::Between`] error is returned
+/// with the span of the matched items.
+///
+/// # Panics
+///
+/// Panics in debug builds if `min > max`, release builds return a parser that always fails.
+#[inline]
+pub fn between<I, F, P>(min: usize, max: usize, mut f: F) -> impl Parser<I, Output = Vec<P::Output>>
+where
+    I: Input + Clone,
+    F: FnMut() -> P,
+    P: Parser<I>,
+{
+    debug_assert!(min <= max, "`between` with `min` {} > `max` {}", min, max);
+
+    move |input: &mut I| {
+        let start = input.clone();
+        let mut outputs = vec![];
+
+        while outputs.len() < max {
+            let Some(output) = f().ensure_progress().ok().parse(input)? else {
+                break;
+            };
+
+            outputs.push(output);
+        }
+
+        if outputs.len() < min {
+            let span = start.to_span_at(start.len() - input.len());
+            *input = start;
+            return Err(Kind::Between(ControlFlow::Recovable, span).into());
+        }
+
+        Ok(outputs)
+    }
+}
+
+/// An iterator pulling the outputs of a parser one at a time, see [`tokens`].
+pub struct TokenIter<I, F> {
+    input: I,
+    f: F,
+    done: bool,
+}
+
+impl<I, F> TokenIter<I, F> {
+    /// Returns the input not consumed yet.
+    pub fn input(&self) -> &I {
+        &self.input
+    }
+
+    /// Returns the input not consumed yet, consuming the iterator.
+    pub fn into_input(self) -> I {
+        self.input
+    }
+}
+
+impl<I, F, P> Iterator for TokenIter<I, F>
+where
+    I: Input,
+    F: FnMut() -> P,
+    P: Parser<I>,
+{
+    type Item = Result<P::Output, I::Error>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done || self.input.is_empty() {
+            return None;
+        }
+
+        let len = self.input.len();
+
+        let result = (self.f)().parse(&mut self.input);
+
+        // stop after an error, or a match consuming nothing that would repeat forever.
+        self.done = result.is_err() || self.input.len() == len;
+
+        Some(result)
+    }
+}
+
+/// Turns the parser created by `f` into an [`Iterator`] over `input`, parsing lazily one output per call.
+///
+/// The iteration ends when the input is empty. A parser error, recoverable or not,
+/// is yielded as the last item, and a match that consumes nothing is the last item too.
+///
+/// ```
+/// use parserc::{Kind, Parser, chars::TokenStream, next_if, tokens};
+///
+/// let digits = tokens(TokenStream::<Kind>::from("123"), || {
+///     next_if(|c: char| c.is_ascii_digit())
+/// });
+///
+/// assert_eq!(
+///     digits.map(|d| d.unwrap().value).collect::<Vec<_>>(),
+///     vec!["1", "2", "3"]
+/// );
+/// ```
+#[inline]
+pub fn tokens<I, F, P>(input: I, f: F) -> TokenIter<I, F>
+where
+    I: Input,
+    F: FnMut() -> P,
+    P: Parser<I>,
+{
+    TokenIter {
+        input,
+        f,
+        done: false,
+    }
+}
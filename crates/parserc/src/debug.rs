@@ -0,0 +1,147 @@
+//! Structured parse traces for debugging grammars.
+//!
+//! Wrap the interesting sub-parsers with [`Parser::debug`], sharing one [`ParseTrace`],
+//! then inspect the recorded tree after parsing:
+//!
+//! ```
+//! use parserc::{Kind, Parser, chars::TokenStream, debug::ParseTrace, next};
+//!
+//! let trace = ParseTrace::new();
+//! let mut input = TokenStream::<Kind>::from("ab");
+//!
+//! (|input: &mut TokenStream<'static>| {
+//!     next('a').debug("a", &trace).parse(input)?;
+//!     next('c').debug("c", &trace).ok().parse(input)
+//! })
+//! .debug("pair", &trace)
+//! .parse(&mut input)
+//! .unwrap();
+//!
+//! assert_eq!(trace.pretty_print(), "pair @0 ok\n  a @0 ok\n  c @1 failed\n");
+//! ```
+
+use alloc::{rc::Rc, string::String, vec, vec::Vec};
+use core::{cell::RefCell, fmt::Write};
+
+use crate::{input::Input, parser::Parser};
+
+/// A node of the [`ParseTrace`] tree, one attempted sub-parse.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct TraceNode {
+    /// Label passed to [`Parser::debug`].
+    pub label: &'static str,
+    /// Start position of the input in the whole source code.
+    pub offset: usize,
+    /// Whether the sub-parse succeeded.
+    pub ok: bool,
+    /// Sub-parses attempted while running this one.
+    pub children: Vec<TraceNode>,
+}
+
+impl TraceNode {
+    fn pretty_print(&self, depth: usize, out: &mut String) {
+        let result = if self.ok { "ok" } else { "failed" };
+
+        writeln!(
+            out,
+            "{:indent$}{} @{} {}",
+            "",
+            self.label,
+            self.offset,
+            result,
+            indent = depth * 2
+        )
+        .unwrap();
+
+        for child in &self.children {
+            child.pretty_print(depth + 1, out);
+        }
+    }
+}
+
+#[derive(Default)]
+struct TraceState {
+    /// Sub-parses in progress, the innermost last.
+    stack: Vec<TraceNode>,
+    /// Finished top-level sub-parses.
+    roots: Vec<TraceNode>,
+}
+
+/// A shared recorder of [`DebugParser`] attempts, cheap to clone.
+#[derive(Default, Clone)]
+pub struct ParseTrace(Rc<RefCell<TraceState>>);
+
+impl ParseTrace {
+    /// Creates an empty trace.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the finished top-level nodes, in parsing order.
+    pub fn roots(&self) -> Vec<TraceNode> {
+        self.0.borrow().roots.clone()
+    }
+
+    /// Removes all finished nodes.
+    pub fn clear(&self) {
+        self.0.borrow_mut().roots.clear();
+    }
+
+    /// Renders the finished nodes as an indented tree, one node per line.
+    pub fn pretty_print(&self) -> String {
+        let mut out = String::new();
+
+        for root in &self.0.borrow().roots {
+            root.pretty_print(0, &mut out);
+        }
+
+        out
+    }
+
+    fn enter(&self, label: &'static str, offset: usize) {
+        self.0.borrow_mut().stack.push(TraceNode {
+            label,
+            offset,
+            ok: false,
+            children: vec![],
+        });
+    }
+
+    fn exit(&self, ok: bool) {
+        let mut state = self.0.borrow_mut();
+
+        let mut node = state.stack.pop().expect("unbalanced trace");
+        node.ok = ok;
+
+        match state.stack.last_mut() {
+            Some(parent) => parent.children.push(node),
+            None => state.roots.push(node),
+        }
+    }
+}
+
+/// A parser that records its attempts into a [`ParseTrace`], see [`Parser::debug`].
+pub struct DebugParser<P> {
+    pub(crate) parser: P,
+    pub(crate) label: &'static str,
+    pub(crate) trace: ParseTrace,
+}
+
+impl<P, I> Parser<I> for DebugParser<P>
+where
+    I: Input,
+    P: Parser<I>,
+{
+    type Output = P::Output;
+
+    #[inline]
+    fn parse(self, input: &mut I) -> Result<Self::Output, I::Error> {
+        self.trace.enter(self.label, input.start());
+
+        let result = self.parser.parse(input);
+
+        self.trace.exit(result.is_ok());
+
+        result
+    }
+}
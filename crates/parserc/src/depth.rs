@@ -0,0 +1,106 @@
+//! Nesting depth limit, a defense against stack overflow on deeply nested input.
+
+use alloc::rc::Rc;
+use core::{cell::Cell, fmt::Debug};
+
+use crate::{
+    errors::{ControlFlow, Kind},
+    input::{Input, delegate_input},
+    parser::Parser,
+};
+
+/// An [`Input`] wrapper that threads the current nesting depth of [`depth_limit`] parsers.
+///
+/// All inputs split or cloned from the same source share one counter.
+pub struct DepthInput<I> {
+    inner: I,
+    depth: Rc<Cell<usize>>,
+}
+
+impl<I> DepthInput<I> {
+    /// Wraps `inner` with a nesting depth of `0`.
+    pub fn new(inner: I) -> Self {
+        Self {
+            inner,
+            depth: Default::default(),
+        }
+    }
+
+    /// Returns the wrapped input.
+    pub fn into_inner(self) -> I {
+        self.inner
+    }
+
+    /// Returns the current nesting depth.
+    pub fn depth(&self) -> usize {
+        self.depth.get()
+    }
+
+    #[inline]
+    fn with_inner(&self, inner: I) -> Self {
+        Self {
+            inner,
+            depth: self.depth.clone(),
+        }
+    }
+}
+
+impl<I> Clone for DepthInput<I>
+where
+    I: Clone,
+{
+    fn clone(&self) -> Self {
+        self.with_inner(self.inner.clone())
+    }
+}
+
+impl<I> Debug for DepthInput<I>
+where
+    I: Debug,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        self.inner.fmt(f)
+    }
+}
+
+impl<I> PartialEq for DepthInput<I>
+where
+    I: PartialEq,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.inner == other.inner
+    }
+}
+
+delegate_input!(DepthInput {
+    front: with_inner,
+    back: with_inner,
+});
+
+/// Runs `parser` one nesting level deeper, wrap the recursive rules of a grammar with it.
+///
+/// Returns a fatal [`Kind::DepthLimit`] error instead of running `parser`
+/// if the nesting depth would exceed `max`.
+///
+/// Unlike [`Kind::LeftRecursion`], which catches recursion that makes no progress,
+/// this bounds recursion that does consume input, e.g. a million nested parentheses.
+#[inline]
+pub fn depth_limit<I, P>(parser: P, max: usize) -> impl Parser<DepthInput<I>, Output = P::Output>
+where
+    I: Input,
+    P: Parser<DepthInput<I>>,
+{
+    move |input: &mut DepthInput<I>| {
+        let depth = input.depth.get();
+
+        if depth >= max {
+            return Err(Kind::DepthLimit(ControlFlow::Fatal, input.to_span_at(1)).into());
+        }
+
+        input.depth.set(depth + 1);
+        let result = parser.parse(input);
+        input.depth.set(depth);
+
+        result
+    }
+}
@@ -1,4 +1,79 @@
-use crate::Span;
+use alloc::borrow::Cow;
+use core::fmt;
+
+use crate::{Span, input::Item};
+
+/// The input expected by a [`Kind::Expected`] error.
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
+pub enum Expected {
+    /// A literal item, e.g. from [`next`](crate::next).
+    Char(char),
+    /// A literal keyword, e.g. from [`keyword`](crate::keyword).
+    Keyword(Cow<'static, str>),
+    /// One of the literal keywords, e.g. from [`keywords`](crate::keywords).
+    OneOf(&'static [&'static str]),
+    /// A description of the expected input, e.g. `decimal digit`.
+    Label(&'static str),
+}
+
+impl fmt::Display for Expected {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Expected::Char(c) => write!(f, "`{}`", c.escape_debug()),
+            Expected::Keyword(keyword) => write!(f, "`{}`", keyword.escape_debug()),
+            Expected::OneOf([keyword]) => write!(f, "`{}`", keyword.escape_debug()),
+            Expected::OneOf(list) => {
+                f.write_str("one of ")?;
+
+                for (index, keyword) in list.iter().enumerate() {
+                    if index > 0 {
+                        f.write_str(", ")?;
+                    }
+
+                    write!(f, "`{}`", keyword.escape_debug())?;
+                }
+
+                Ok(())
+            }
+            Expected::Label(label) => f.write_str(label),
+        }
+    }
+}
+
+/// The input found by a [`Kind::Expected`] error instead.
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
+pub enum Found {
+    /// The end of the input.
+    Eof,
+    /// An item with a `char` form, see [`Item::to_char`].
+    Char(char),
+    /// An item without a `char` form, e.g. a non-ASCII byte.
+    Other,
+}
+
+/// Classifies the next item of an input, `None` at the end of the input.
+impl<T> From<Option<T>> for Found
+where
+    T: Item,
+{
+    #[inline]
+    fn from(item: Option<T>) -> Self {
+        match item {
+            Some(item) => item.to_char().map_or(Found::Other, Found::Char),
+            None => Found::Eof,
+        }
+    }
+}
+
+impl fmt::Display for Found {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Found::Eof => f.write_str("end of input"),
+            Found::Char(c) => write!(f, "`{}`", c.escape_debug()),
+            Found::Other => f.write_str("a non-character item"),
+        }
+    }
+}
 
 /// A variant type to control error handle.
 #[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Clone, Copy, Hash)]
@@ -12,15 +87,44 @@ pub enum ControlFlow {
     Incomplete,
 }
 
+impl ControlFlow {
+    /// Upgrades any error to [`Fatal`](ControlFlow::Fatal).
+    #[inline]
+    pub fn escalate(self) -> Self {
+        ControlFlow::Fatal
+    }
+
+    /// Downgrades [`Fatal`](ControlFlow::Fatal) to [`Recovable`](ControlFlow::Recovable),
+    /// other values are unchanged.
+    #[inline]
+    pub fn deescalate(self) -> Self {
+        match self {
+            ControlFlow::Fatal => ControlFlow::Recovable,
+            control_flow => control_flow,
+        }
+    }
+}
+
 /// Error kind returns by builtin parser combinators.
 #[derive(thiserror::Error, Debug, PartialEq, Eq, Hash, Clone)]
 pub enum Kind {
+    #[deprecated(note = "`next` returns `Kind::Expected` instead")]
     #[error("Error from `next` combinator")]
     Next(ControlFlow, Span),
+    #[deprecated(note = "`next_if` returns `Kind::Expected` instead")]
     #[error("Error from `next_if` combinator")]
     NextIf(ControlFlow, Span),
+    #[deprecated(note = "`keyword` returns `Kind::Expected` instead")]
     #[error("Error from `keyword` combinator")]
     Keyword(ControlFlow, Span),
+    /// The input does not match `what`, `found` is the first item of the input.
+    #[error("expected {what}, found {found}")]
+    Expected {
+        what: Expected,
+        found: Found,
+        span: Span,
+        control_flow: ControlFlow,
+    },
     #[error("Error from parsing syntax `{0}`")]
     Syntax(&'static str, ControlFlow, Span),
     #[error("Error from parsing token `{0}`")]
@@ -41,6 +145,30 @@ pub enum Kind {
     TakeWhileTo(ControlFlow, Span),
     #[error("Detected `left recursion`")]
     LeftRecursion(ControlFlow, Span),
+    #[error("Error from parsing syntax `Punctuated`, expect element after separator")]
+    Punctuated(ControlFlow, Span),
+    #[error("Error from `take_while_streaming`")]
+    TakeWhile(ControlFlow, Span),
+    #[error("Error from `permutation`, expect one of the remaining parsers to match")]
+    Permutation(ControlFlow, Span),
+    #[error("Error from `terminated_by_eof`, unparsed input remains")]
+    Eof(ControlFlow, Span),
+    #[error("Error from `not_followed_by`, the parser is followed by a forbidden match")]
+    NotFollowedBy(ControlFlow, Span),
+    #[error("Error from `with_fuel`, the fuel ran out")]
+    FuelExhausted(ControlFlow, Span),
+    #[error("Error from `depth_limit`, nesting is too deep")]
+    DepthLimit(ControlFlow, Span),
+    #[error("Error from `between`, too few repetitions")]
+    Between(ControlFlow, Span),
+    #[error("Error from `dec_uint`/`dec_int`, the number is out of range of the target type")]
+    Overflow(ControlFlow, Span),
+    #[error("Error from parsing `Map`, the key is duplicated")]
+    DuplicateKey(ControlFlow, Span),
+    #[error("Error from `verify_map`, the output is rejected")]
+    Verify(ControlFlow, Span),
+    #[error("Error from a repetition, the parser succeeded without consuming input")]
+    NoProgress(ControlFlow, Span),
 }
 
 /// A error type returns by parser combinators.
@@ -52,6 +180,43 @@ pub trait ParseError: From<Kind> {
     /// Ensure this error is an fatal error.
     fn into_fatal(self) -> Self;
 
+    /// Returns a key to order errors by source position: the span start, then the span end.
+    ///
+    /// Unbounded ends sort as `usize::MAX`, and [`Span::None`] sorts after every other span.
+    ///
+    /// ```
+    /// use parserc::{ControlFlow, Kind, ParseError, Span};
+    ///
+    /// let mut errors = vec![
+    ///     Kind::Eof(ControlFlow::Recovable, Span::None),
+    ///     Kind::Eof(ControlFlow::Recovable, Span::Range(4..5)),
+    ///     Kind::Eof(ControlFlow::Recovable, Span::Range(1..3)),
+    /// ];
+    /// errors.sort_by_key(ParseError::sort_key);
+    ///
+    /// assert_eq!(errors[0].to_span(), Span::Range(1..3));
+    /// assert_eq!(errors[2].to_span(), Span::None);
+    /// ```
+    #[inline]
+    fn sort_key(&self) -> (usize, usize) {
+        match self.to_span() {
+            Span::None => (usize::MAX, usize::MAX),
+            Span::Range(range) => (range.start, range.end),
+            Span::RangeFrom(range) => (range.start, usize::MAX),
+            Span::RangeTo(range) => (0, range.end),
+            Span::RangeFull => (0, usize::MAX),
+        }
+    }
+
+    /// Upgrades this error to a fatal error, same as [`into_fatal`](ParseError::into_fatal).
+    #[inline]
+    fn escalate(self) -> Self
+    where
+        Self: Sized,
+    {
+        self.into_fatal()
+    }
+
     /// Returns true if it's `control_flow == ControlFlow::Fatal`
     #[inline]
     fn is_fatal(&self) -> bool {
@@ -59,10 +224,90 @@ pub trait ParseError: From<Kind> {
     }
 }
 
+/// A [`ParseError`] that can be downgraded, see [`Parser::recoverable`](crate::Parser::recoverable).
+pub trait Deescalate: ParseError {
+    /// Downgrades a fatal error to a recoverable error at the same span,
+    /// see [`ControlFlow::deescalate`].
+    fn deescalate(self) -> Self;
+}
+
+/// An error kind that builds the error `E` at the `ControlFlow` and span of another error,
+/// see [`Parser::map_err_kind`](crate::Parser::map_err_kind).
+pub trait IntoErrorKind<E> {
+    /// Creates the error `E` of this kind.
+    fn into_error(self, control_flow: ControlFlow, span: Span) -> E;
+}
+
+/// Error constructors, e.g. `Kind::TakeWhile`.
+impl<F, E> IntoErrorKind<E> for F
+where
+    F: FnOnce(ControlFlow, Span) -> E,
+{
+    #[inline]
+    fn into_error(self, control_flow: ControlFlow, span: Span) -> E {
+        self(control_flow, span)
+    }
+}
+
+/// Syntax names, creates a [`Kind::Syntax`] error.
+impl<E> IntoErrorKind<E> for &'static str
+where
+    E: From<Kind>,
+{
+    #[inline]
+    fn into_error(self, control_flow: ControlFlow, span: Span) -> E {
+        Kind::Syntax(self, control_flow, span).into()
+    }
+}
+
+#[allow(deprecated)]
+impl Kind {
+    /// Returns this error with its `ControlFlow` replaced by `control_flow`.
+    pub fn with_control_flow(self, control_flow: ControlFlow) -> Self {
+        match self {
+            Kind::Next(_, span) => Kind::Next(control_flow, span),
+            Kind::Expected {
+                what, found, span, ..
+            } => Kind::Expected {
+                what,
+                found,
+                span,
+                control_flow,
+            },
+            Kind::NextIf(_, span) => Kind::NextIf(control_flow, span),
+            Kind::Keyword(_, span) => Kind::Keyword(control_flow, span),
+            Kind::TakeUntil(_, span) => Kind::TakeUntil(control_flow, span),
+            Kind::TakeWhileRange(_, span) => Kind::TakeWhileRange(control_flow, span),
+            Kind::TakeWhileFrom(_, span) => Kind::TakeWhileFrom(control_flow, span),
+            Kind::TakeWhileTo(_, span) => Kind::TakeWhileTo(control_flow, span),
+            Kind::Syntax(name, _, span) => Kind::Syntax(name, control_flow, span),
+            Kind::Token(name, _, span) => Kind::Token(name, control_flow, span),
+            Kind::LimitsTo(_, span) => Kind::LimitsTo(control_flow, span),
+            Kind::Limits(_, span) => Kind::Limits(control_flow, span),
+            Kind::LimitsFrom(_, span) => Kind::LimitsFrom(control_flow, span),
+            Kind::LeftRecursion(_, span) => Kind::LeftRecursion(control_flow, span),
+            Kind::Punctuated(_, span) => Kind::Punctuated(control_flow, span),
+            Kind::TakeWhile(_, span) => Kind::TakeWhile(control_flow, span),
+            Kind::Permutation(_, span) => Kind::Permutation(control_flow, span),
+            Kind::Eof(_, span) => Kind::Eof(control_flow, span),
+            Kind::NotFollowedBy(_, span) => Kind::NotFollowedBy(control_flow, span),
+            Kind::FuelExhausted(_, span) => Kind::FuelExhausted(control_flow, span),
+            Kind::DepthLimit(_, span) => Kind::DepthLimit(control_flow, span),
+            Kind::Between(_, span) => Kind::Between(control_flow, span),
+            Kind::Overflow(_, span) => Kind::Overflow(control_flow, span),
+            Kind::DuplicateKey(_, span) => Kind::DuplicateKey(control_flow, span),
+            Kind::Verify(_, span) => Kind::Verify(control_flow, span),
+            Kind::NoProgress(_, span) => Kind::NoProgress(control_flow, span),
+        }
+    }
+}
+
+#[allow(deprecated)]
 impl ParseError for Kind {
     fn control_flow(&self) -> ControlFlow {
         match self {
             Kind::Next(control_flow, _) => *control_flow,
+            Kind::Expected { control_flow, .. } => *control_flow,
             Kind::NextIf(control_flow, _) => *control_flow,
             Kind::Keyword(control_flow, _) => *control_flow,
             Kind::Syntax(_, control_flow, _) => *control_flow,
@@ -75,30 +320,29 @@ impl ParseError for Kind {
             Kind::TakeWhileFrom(control_flow, _) => *control_flow,
             Kind::TakeWhileTo(control_flow, _) => *control_flow,
             Kind::LeftRecursion(control_flow, _) => *control_flow,
+            Kind::Punctuated(control_flow, _) => *control_flow,
+            Kind::TakeWhile(control_flow, _) => *control_flow,
+            Kind::Permutation(control_flow, _) => *control_flow,
+            Kind::Eof(control_flow, _) => *control_flow,
+            Kind::NotFollowedBy(control_flow, _) => *control_flow,
+            Kind::FuelExhausted(control_flow, _) => *control_flow,
+            Kind::DepthLimit(control_flow, _) => *control_flow,
+            Kind::Between(control_flow, _) => *control_flow,
+            Kind::Overflow(control_flow, _) => *control_flow,
+            Kind::DuplicateKey(control_flow, _) => *control_flow,
+            Kind::Verify(control_flow, _) => *control_flow,
+            Kind::NoProgress(control_flow, _) => *control_flow,
         }
     }
 
     fn into_fatal(self) -> Self {
-        match self {
-            Kind::Next(_, span) => Kind::Next(ControlFlow::Fatal, span),
-            Kind::NextIf(_, span) => Kind::NextIf(ControlFlow::Fatal, span),
-            Kind::Keyword(_, span) => Kind::Keyword(ControlFlow::Fatal, span),
-            Kind::TakeUntil(_, span) => Kind::TakeUntil(ControlFlow::Fatal, span),
-            Kind::TakeWhileRange(_, span) => Kind::TakeWhileRange(ControlFlow::Fatal, span),
-            Kind::TakeWhileFrom(_, span) => Kind::TakeWhileFrom(ControlFlow::Fatal, span),
-            Kind::TakeWhileTo(_, span) => Kind::TakeWhileTo(ControlFlow::Fatal, span),
-            Kind::Syntax(name, _, span) => Kind::Syntax(name, ControlFlow::Fatal, span),
-            Kind::Token(name, _, span) => Kind::Token(name, ControlFlow::Fatal, span),
-            Kind::LimitsTo(_, span) => Kind::LimitsTo(ControlFlow::Fatal, span),
-            Kind::Limits(_, span) => Kind::Limits(ControlFlow::Fatal, span),
-            Kind::LimitsFrom(_, span) => Kind::LimitsFrom(ControlFlow::Fatal, span),
-            Kind::LeftRecursion(_, span) => Kind::LeftRecursion(ControlFlow::Fatal, span),
-        }
+        self.with_control_flow(ControlFlow::Fatal)
     }
 
     fn to_span(&self) -> Span {
         match self {
             Kind::Next(_, span) => span.clone(),
+            Kind::Expected { span, .. } => span.clone(),
             Kind::NextIf(_, span) => span.clone(),
             Kind::Keyword(_, span) => span.clone(),
             Kind::Syntax(_, _, span) => span.clone(),
@@ -111,6 +355,25 @@ impl ParseError for Kind {
             Kind::TakeWhileTo(_, span) => span.clone(),
             Kind::LimitsFrom(_, span) => span.clone(),
             Kind::LeftRecursion(_, span) => span.clone(),
+            Kind::Punctuated(_, span) => span.clone(),
+            Kind::TakeWhile(_, span) => span.clone(),
+            Kind::Permutation(_, span) => span.clone(),
+            Kind::Eof(_, span) => span.clone(),
+            Kind::NotFollowedBy(_, span) => span.clone(),
+            Kind::FuelExhausted(_, span) => span.clone(),
+            Kind::DepthLimit(_, span) => span.clone(),
+            Kind::Between(_, span) => span.clone(),
+            Kind::Overflow(_, span) => span.clone(),
+            Kind::DuplicateKey(_, span) => span.clone(),
+            Kind::Verify(_, span) => span.clone(),
+            Kind::NoProgress(_, span) => span.clone(),
         }
     }
 }
+
+impl Deescalate for Kind {
+    fn deescalate(self) -> Self {
+        let control_flow = self.control_flow().deescalate();
+        self.with_control_flow(control_flow)
+    }
+}
@@ -0,0 +1,150 @@
+//! Fuel-bounded parsing, a defense against pathological backtracking on untrusted input.
+
+use alloc::rc::Rc;
+use core::{cell::Cell, fmt::Debug};
+
+use crate::{
+    errors::{ControlFlow, Kind},
+    input::{Input, delegate_input},
+    parser::Parser,
+};
+
+/// An input that tracks a shared fuel budget, see [`FuelInput`].
+pub trait Fuel {
+    /// Returns the remaining fuel, `None` once it has run out.
+    fn fuel(&self) -> Option<usize>;
+
+    /// Sets the remaining fuel, `None` marks it as run out.
+    fn set_fuel(&self, fuel: Option<usize>);
+
+    /// Returns true if the fuel has run out.
+    #[inline]
+    fn is_exhausted(&self) -> bool {
+        self.fuel().is_none()
+    }
+}
+
+/// An [`Input`] wrapper that burns one unit of fuel each time it is cloned.
+///
+/// Combinators clone the input to backtrack, e.g. [`ok`](Parser::ok) and [`or`](Parser::or)
+/// clone it once per attempt, so the fuel bounds the amount of backtracking.
+/// All inputs split from the same source share one budget.
+///
+/// Once the fuel runs out, the content of the input reads as empty: `len`, `iter`, `peek`, `as_str`,
+/// `starts_with`, `find` and friends all agree, only `start` and `end` keep their positions for spans.
+/// Every pending parser fails fast, and [`with_fuel`](Parser::with_fuel) replaces whatever error
+/// they report with a [`Kind::FuelExhausted`] error.
+pub struct FuelInput<I> {
+    inner: I,
+    /// `None` means the fuel has run out.
+    fuel: Rc<Cell<Option<usize>>>,
+}
+
+impl<I> FuelInput<I> {
+    /// Wraps `inner` with a total budget of `fuel` clones, that no [`with_fuel`](Parser::with_fuel)
+    /// can raise.
+    pub fn new(inner: I, fuel: usize) -> Self {
+        Self {
+            inner,
+            fuel: Rc::new(Cell::new(Some(fuel))),
+        }
+    }
+
+    /// Returns the wrapped input.
+    pub fn into_inner(self) -> I {
+        self.inner
+    }
+
+    /// Returns the remaining fuel.
+    pub fn remaining(&self) -> usize {
+        self.fuel.get().unwrap_or_default()
+    }
+
+    #[inline]
+    fn with_inner(&self, inner: I) -> Self {
+        Self {
+            inner,
+            fuel: self.fuel.clone(),
+        }
+    }
+}
+
+impl<I> Fuel for FuelInput<I> {
+    #[inline]
+    fn fuel(&self) -> Option<usize> {
+        self.fuel.get()
+    }
+
+    #[inline]
+    fn set_fuel(&self, fuel: Option<usize>) {
+        self.fuel.set(fuel);
+    }
+}
+
+impl<I> Clone for FuelInput<I>
+where
+    I: Clone,
+{
+    fn clone(&self) -> Self {
+        self.fuel
+            .set(self.fuel.get().and_then(|fuel| fuel.checked_sub(1)));
+
+        self.with_inner(self.inner.clone())
+    }
+}
+
+impl<I> Debug for FuelInput<I>
+where
+    I: Debug,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        self.inner.fmt(f)
+    }
+}
+
+impl<I> PartialEq for FuelInput<I>
+where
+    I: PartialEq,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.inner == other.inner
+    }
+}
+
+delegate_input!(FuelInput {
+    front: with_inner,
+    back: with_inner,
+    exhausted: is_exhausted,
+});
+
+pub(crate) struct WithFuel<P>(pub(crate) P, pub(crate) usize);
+
+impl<P, I> Parser<I> for WithFuel<P>
+where
+    I: Input + Fuel,
+    P: Parser<I>,
+{
+    type Output = P::Output;
+
+    #[inline]
+    fn parse(self, input: &mut I) -> Result<Self::Output, I::Error> {
+        let Some(outer) = input.fuel() else {
+            return Err(Kind::FuelExhausted(ControlFlow::Fatal, input.to_span_at(1)).into());
+        };
+
+        let budget = outer.min(self.1);
+        input.set_fuel(Some(budget));
+
+        let result = self.0.parse(input);
+
+        // charge the enclosing budget with the fuel spent, the clone that ran out counts too.
+        let Some(left) = input.fuel() else {
+            input.set_fuel((outer - budget).checked_sub(1));
+            return Err(Kind::FuelExhausted(ControlFlow::Fatal, input.to_span_at(1)).into());
+        };
+
+        input.set_fuel(Some(outer - (budget - left)));
+
+        result
+    }
+}
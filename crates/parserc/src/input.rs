@@ -1,4 +1,5 @@
-use std::{cmp, fmt::Debug};
+use alloc::vec::Vec;
+use core::{cmp, fmt::Debug, str::Utf8Error};
 
 use crate::{ParseError, Span};
 
@@ -14,6 +15,13 @@ pub trait Find<Needle> {
     fn find(&self, needle: Needle) -> Option<usize>;
 }
 
+/// An extension trait provides extra `find_all` func to `Input`.
+pub trait FindAll<Needle> {
+    /// Returns the byte offsets, relative to the input front, of all non-overlapping occurrences
+    /// of the given needle, in order.
+    fn find_all(&self, needle: Needle) -> Vec<usize>;
+}
+
 /// Convert `Input` as `&[u8]`
 pub trait AsBytes {
     /// Convert the input type to a byte slice
@@ -24,18 +32,40 @@ pub trait AsBytes {
 pub trait Length {
     /// Returns item length.
     fn len(&self) -> usize;
+
+    /// Returns true if the item length is 0.
+    #[inline]
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns this value as text, if it has a text form, used by error reporting.
+    #[inline]
+    fn as_text(&self) -> Option<&str> {
+        None
+    }
 }
 
 impl Length for &str {
     fn len(&self) -> usize {
         str::len(self)
     }
+
+    #[inline]
+    fn as_text(&self) -> Option<&str> {
+        Some(self)
+    }
 }
 
 impl Length for &[u8] {
     fn len(&self) -> usize {
         <[u8]>::len(self)
     }
+
+    #[inline]
+    fn as_text(&self) -> Option<&str> {
+        core::str::from_utf8(self).ok()
+    }
 }
 
 /// Convert `Input` as `&str`
@@ -44,9 +74,37 @@ pub trait AsStr {
     fn as_str(&self) -> &str;
 }
 
+/// Convert `Input` as `&str`, failing if the input is not valid UTF-8.
+pub trait TryAsStr {
+    /// Convert the input type to a string slice, or returns the UTF-8 error.
+    fn try_as_str(&self) -> Result<&str, Utf8Error>;
+}
+
+impl<T> TryAsStr for T
+where
+    T: AsStr,
+{
+    #[inline]
+    fn try_as_str(&self) -> Result<&str, Utf8Error> {
+        Ok(self.as_str())
+    }
+}
+
 /// The item type of the input sequence.
 pub trait Item: PartialEq + Clone + Copy + Debug {
     fn len(&self) -> usize;
+
+    /// Returns this item as a `char`, if it represents one, used by error reporting.
+    #[inline]
+    fn to_char(&self) -> Option<char> {
+        None
+    }
+
+    /// Returns true if the item length is 0.
+    #[inline]
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
 }
 
 impl Item for u8 {
@@ -54,6 +112,11 @@ impl Item for u8 {
     fn len(&self) -> usize {
         1
     }
+
+    #[inline]
+    fn to_char(&self) -> Option<char> {
+        self.is_ascii().then_some(*self as char)
+    }
 }
 
 impl Item for char {
@@ -61,6 +124,11 @@ impl Item for char {
     fn len(&self) -> usize {
         self.len_utf8()
     }
+
+    #[inline]
+    fn to_char(&self) -> Option<char> {
+        Some(*self)
+    }
 }
 
 /// Input sequence for source code.
@@ -87,12 +155,87 @@ pub trait Input: PartialEq + Debug {
     /// Afterwards self contains elements [0, at), and the returned `Self` contains elements [at, capacity).
     fn split_off(&mut self, at: usize) -> Self;
 
+    /// Like [`split_to`](Input::split_to), but returns `None` and leaves the input unchanged,
+    /// instead of panicking, if `at` is out of bounds or does not fall on an item boundary,
+    /// e.g. in the middle of a multi-byte char.
+    ///
+    /// The default implementation only checks the bounds, for inputs whose every position
+    /// is an item boundary.
+    #[inline]
+    fn checked_split_to(&mut self, at: usize) -> Option<Self>
+    where
+        Self: Sized,
+    {
+        (at <= self.len()).then(|| self.split_to(at))
+    }
+
     /// Returns an immutable iterator over source code chars.
     fn iter(&self) -> Self::Iter;
 
-    /// Returns an immutable iterator over source code chars.
+    /// Returns an immutable iterator over source code chars and their positions.
+    ///
+    /// The positions are byte offsets relative to the start of this input, not item counts,
+    /// so they can be passed directly to [`split_to`](Input::split_to) and
+    /// [`split_off`](Input::split_off). For a chars input with multi-byte chars
+    /// the positions therefore skip values.
     fn iter_indices(&self) -> Self::IterIndices;
 
+    /// An alias of [`iter_indices`](Input::iter_indices), the positions are byte offsets.
+    #[inline]
+    fn char_indices(&self) -> Self::IterIndices {
+        self.iter_indices()
+    }
+
+    /// Returns the first item without consuming it, same as `self.iter().next()`.
+    #[inline]
+    fn peek(&self) -> Option<Self::Item> {
+        self.iter().next()
+    }
+
+    /// Returns a clone of this input sliced to at most the first `n` items, without consuming them.
+    ///
+    /// If fewer than `n` items remain, returns the whole input.
+    #[inline]
+    fn peek_n(&self, n: usize) -> Self
+    where
+        Self: Clone,
+    {
+        let offset = self
+            .iter_indices()
+            .nth(n)
+            .map(|(offset, _)| offset)
+            .unwrap_or(self.len());
+
+        let mut prefix = self.clone();
+        prefix.split_off(offset);
+        prefix
+    }
+
+    /// Shifts the positions of this input by `base`, so the spans of a chunk parsed on its own
+    /// are absolute positions in the enclosing document.
+    ///
+    /// The default implementation returns the input unchanged, for inputs without positions.
+    #[inline]
+    fn with_offset(self, base: usize) -> Self
+    where
+        Self: Sized,
+    {
+        _ = base;
+        self
+    }
+
+    /// Returns an iterator over the lines of this input, as sub-inputs keeping their positions.
+    ///
+    /// Lines are split on `\n`, and the line terminator, `\n` or `\r\n`, is not included.
+    /// Like [`str::lines`], a final line terminator does not yield an extra empty line.
+    #[inline]
+    fn lines(&self) -> Lines<Self>
+    where
+        Self: AsBytes + Clone + Sized,
+    {
+        Lines(Some(self.clone()))
+    }
+
     /// Returns the start position of this input in the whole source code.
     fn start(&self) -> usize;
 
@@ -118,6 +261,35 @@ pub trait Input: PartialEq + Debug {
     }
 }
 
+/// An iterator over the lines of an input, see [`Input::lines`].
+#[derive(Debug, Clone)]
+pub struct Lines<I>(Option<I>);
+
+impl<I> Iterator for Lines<I>
+where
+    I: Input + AsBytes + Clone,
+{
+    type Item = I;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let input = self.0.as_mut()?;
+
+        let Some(offset) = input.as_bytes().iter().position(|b| *b == b'\n') else {
+            let line = self.0.take()?;
+            return (!line.is_empty()).then_some(line);
+        };
+
+        let mut line = input.split_to(offset + 1);
+        line.split_off(offset);
+
+        if line.as_bytes().last() == Some(&b'\r') {
+            line.split_off(offset - 1);
+        }
+
+        Some(line)
+    }
+}
+
 /// A trait provides `to_span` func.
 pub trait ToSpan {
     /// Returns the regiion of this input.
@@ -140,7 +312,12 @@ where
 /// bytes input implementation.
 #[cfg(feature = "input")]
 pub mod bytes {
-    use std::{iter::Enumerate, marker::PhantomData, str::Bytes};
+    use core::{
+        iter::{Copied, Enumerate},
+        marker::PhantomData,
+        slice,
+        str::Bytes,
+    };
 
     use memchr::memmem;
 
@@ -185,7 +362,7 @@ pub mod bytes {
     }
 
     impl<'a, E> Debug for TokenStream<'a, E> {
-        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
             write!(f, "TokenStream::from(({},{:?}))", self.offset, self.value)
         }
     }
@@ -235,22 +412,36 @@ pub mod bytes {
 
         #[inline]
         fn split_to(&mut self, at: usize) -> Self {
-            let (first, last) = self.value.split_at(at);
+            let len = self.value.len();
+
+            self.checked_split_to(at).unwrap_or_else(|| {
+                panic!("TokenStream::split_to: index {at} is out of bounds or not a char boundary, input length {len}")
+            })
+        }
+
+        #[inline]
+        fn checked_split_to(&mut self, at: usize) -> Option<Self> {
+            let (first, last) = self.value.split_at_checked(at)?;
 
             self.value = last;
             let offset = self.offset;
             self.offset += at;
 
-            TokenStream {
+            Some(TokenStream {
                 offset,
                 value: first,
                 _marker: Default::default(),
-            }
+            })
         }
 
         #[inline]
         fn split_off(&mut self, at: usize) -> Self {
-            let (first, last) = self.value.split_at(at);
+            let Some((first, last)) = self.value.split_at_checked(at) else {
+                panic!(
+                    "TokenStream::split_off: index {at} is out of bounds or not a char boundary, input length {}",
+                    self.value.len()
+                );
+            };
 
             self.value = first;
 
@@ -271,6 +462,17 @@ pub mod bytes {
             self.iter().enumerate()
         }
 
+        #[inline]
+        fn peek(&self) -> Option<Self::Item> {
+            self.value.as_bytes().first().copied()
+        }
+
+        #[inline]
+        fn with_offset(mut self, base: usize) -> Self {
+            self.offset += base;
+            self
+        }
+
         #[inline]
         fn start(&self) -> usize {
             self.offset
@@ -350,13 +552,231 @@ pub mod bytes {
         }
     }
 
+    impl<'a, E, N> FindAll<N> for TokenStream<'a, E>
+    where
+        N: AsRef<[u8]>,
+    {
+        #[inline]
+        fn find_all(&self, needle: N) -> Vec<usize> {
+            memmem::find_iter(self.as_bytes(), needle.as_ref()).collect()
+        }
+    }
+
     impl<'a, E> BytesInput for TokenStream<'a, E> where E: ParseError + Clone {}
+
+    /// A `&[u8]` backed input, which unlike [`TokenStream`] may contain invalid UTF-8.
+    ///
+    /// It implements [`TryAsStr`] instead of [`AsStr`], so it is not a [`BytesInput`].
+    #[derive(Eq, PartialOrd, Ord, Hash)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+    pub struct ByteStream<'a, Error = Kind> {
+        /// offset in the whole byte stream.
+        pub offset: usize,
+        /// current segement bytes in the whole byte stream.
+        pub value: &'a [u8],
+        /// Error for this input.
+        _marker: PhantomData<Error>,
+    }
+
+    impl<'a, E> Clone for ByteStream<'a, E> {
+        fn clone(&self) -> Self {
+            Self {
+                offset: self.offset,
+                value: self.value,
+                _marker: Default::default(),
+            }
+        }
+    }
+
+    impl<'a, E> Debug for ByteStream<'a, E> {
+        fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+            write!(
+                f,
+                "ByteStream::from(({},b\"{}\"))",
+                self.offset,
+                self.value.escape_ascii()
+            )
+        }
+    }
+
+    impl<'a, E> PartialEq for ByteStream<'a, E> {
+        fn eq(&self, other: &Self) -> bool {
+            self.offset == other.offset && self.value == other.value
+        }
+    }
+
+    impl<'a, E> From<&'a [u8]> for ByteStream<'a, E> {
+        fn from(value: &'a [u8]) -> Self {
+            ByteStream {
+                offset: 0,
+                value,
+                _marker: Default::default(),
+            }
+        }
+    }
+
+    impl<'a, E> From<(usize, &'a [u8])> for ByteStream<'a, E> {
+        fn from(value: (usize, &'a [u8])) -> Self {
+            ByteStream {
+                offset: value.0,
+                value: value.1,
+                _marker: Default::default(),
+            }
+        }
+    }
+
+    impl<'a, E> Input for ByteStream<'a, E>
+    where
+        E: ParseError,
+    {
+        type Item = u8;
+
+        type Error = E;
+
+        type Iter = Copied<slice::Iter<'a, u8>>;
+
+        type IterIndices = Enumerate<Self::Iter>;
+
+        #[inline]
+        fn len(&self) -> usize {
+            self.value.len()
+        }
+
+        #[inline]
+        fn split_to(&mut self, at: usize) -> Self {
+            let (first, last) = self.value.split_at(at);
+
+            self.value = last;
+            let offset = self.offset;
+            self.offset += at;
+
+            ByteStream {
+                offset,
+                value: first,
+                _marker: Default::default(),
+            }
+        }
+
+        #[inline]
+        fn split_off(&mut self, at: usize) -> Self {
+            let (first, last) = self.value.split_at(at);
+
+            self.value = first;
+
+            ByteStream {
+                offset: self.offset + at,
+                value: last,
+                _marker: Default::default(),
+            }
+        }
+
+        #[inline]
+        fn iter(&self) -> Self::Iter {
+            self.value.iter().copied()
+        }
+
+        #[inline]
+        fn iter_indices(&self) -> Self::IterIndices {
+            self.iter().enumerate()
+        }
+
+        #[inline]
+        fn peek(&self) -> Option<Self::Item> {
+            self.value.first().copied()
+        }
+
+        #[inline]
+        fn with_offset(mut self, base: usize) -> Self {
+            self.offset += base;
+            self
+        }
+
+        #[inline]
+        fn start(&self) -> usize {
+            self.offset
+        }
+
+        #[inline]
+        fn end(&self) -> usize {
+            self.offset + self.value.len()
+        }
+    }
+
+    impl<'a, E> AsBytes for ByteStream<'a, E> {
+        #[inline]
+        fn as_bytes(&self) -> &[u8] {
+            self.value
+        }
+    }
+
+    impl<'a, E> TryAsStr for ByteStream<'a, E> {
+        #[inline]
+        fn try_as_str(&self) -> Result<&str, Utf8Error> {
+            core::str::from_utf8(self.value)
+        }
+    }
+
+    impl<'a, E> StartWith<&str> for ByteStream<'a, E> {
+        #[inline]
+        fn starts_with(&self, needle: &str) -> Option<usize> {
+            self.starts_with(needle.as_bytes())
+        }
+    }
+
+    impl<'a, E> StartWith<&[u8]> for ByteStream<'a, E> {
+        #[inline]
+        fn starts_with(&self, needle: &[u8]) -> Option<usize> {
+            if self.value.starts_with(needle) {
+                Some(needle.len())
+            } else {
+                None
+            }
+        }
+    }
+
+    impl<'a, const N: usize, E> StartWith<&[u8; N]> for ByteStream<'a, E> {
+        #[inline]
+        fn starts_with(&self, needle: &[u8; N]) -> Option<usize> {
+            self.starts_with(&needle[..])
+        }
+    }
+
+    impl<'a, E> Find<&str> for ByteStream<'a, E> {
+        #[inline]
+        fn find(&self, needle: &str) -> Option<usize> {
+            memmem::find(self.value, needle.as_bytes())
+        }
+    }
+
+    impl<'a, E> Find<&[u8]> for ByteStream<'a, E> {
+        #[inline]
+        fn find(&self, needle: &[u8]) -> Option<usize> {
+            memmem::find(self.value, needle)
+        }
+    }
+
+    impl<'a, const N: usize, E> Find<&[u8; N]> for ByteStream<'a, E> {
+        #[inline]
+        fn find(&self, needle: &[u8; N]) -> Option<usize> {
+            memmem::find(self.value, needle)
+        }
+    }
+
+    impl<'a, E, N> FindAll<N> for ByteStream<'a, E>
+    where
+        N: AsRef<[u8]>,
+    {
+        #[inline]
+        fn find_all(&self, needle: N) -> Vec<usize> {
+            memmem::find_iter(self.value, needle.as_ref()).collect()
+        }
+    }
 }
 
 /// chars input implementation.
 #[cfg(feature = "input")]
 pub mod chars {
-    use std::{
+    use core::{
         marker::PhantomData,
         str::{CharIndices, Chars},
     };
@@ -402,7 +822,7 @@ pub mod chars {
     }
 
     impl<'a, E> Debug for TokenStream<'a, E> {
-        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
             write!(f, "TokenStream::from(({},{:?}))", self.offset, self.value)
         }
     }
@@ -452,22 +872,36 @@ pub mod chars {
 
         #[inline]
         fn split_to(&mut self, at: usize) -> Self {
-            let (first, last) = self.value.split_at(at);
+            let len = self.value.len();
+
+            self.checked_split_to(at).unwrap_or_else(|| {
+                panic!("TokenStream::split_to: index {at} is out of bounds or not a char boundary, input length {len}")
+            })
+        }
+
+        #[inline]
+        fn checked_split_to(&mut self, at: usize) -> Option<Self> {
+            let (first, last) = self.value.split_at_checked(at)?;
 
             self.value = last;
             let offset = self.offset;
             self.offset += at;
 
-            TokenStream {
+            Some(TokenStream {
                 offset,
                 value: first,
                 _marker: Default::default(),
-            }
+            })
         }
 
         #[inline]
         fn split_off(&mut self, at: usize) -> Self {
-            let (first, last) = self.value.split_at(at);
+            let Some((first, last)) = self.value.split_at_checked(at) else {
+                panic!(
+                    "TokenStream::split_off: index {at} is out of bounds or not a char boundary, input length {}",
+                    self.value.len()
+                );
+            };
 
             self.value = first;
 
@@ -488,6 +922,17 @@ pub mod chars {
             self.value.char_indices()
         }
 
+        #[inline]
+        fn peek(&self) -> Option<Self::Item> {
+            self.value.chars().next()
+        }
+
+        #[inline]
+        fn with_offset(mut self, base: usize) -> Self {
+            self.offset += base;
+            self
+        }
+
         #[inline]
         fn start(&self) -> usize {
             self.offset
@@ -567,5 +1012,184 @@ pub mod chars {
         }
     }
 
+    impl<'a, E, N> FindAll<N> for TokenStream<'a, E>
+    where
+        N: AsRef<[u8]>,
+    {
+        #[inline]
+        fn find_all(&self, needle: N) -> Vec<usize> {
+            memmem::find_iter(self.as_bytes(), needle.as_ref()).collect()
+        }
+    }
+
     impl<'a, E> CharsInput for TokenStream<'a, E> where E: ParseError + Clone {}
 }
+
+/// Implements [`Input`], [`AsBytes`], [`AsStr`], [`StartWith`], [`Find`], [`FindAll`] and the
+/// `BytesInput`/`CharsInput` markers for an input wrapper, delegating to its `inner: I` field.
+///
+/// `front` and `back` name the wrapper methods that wrap the inner parts split off by
+/// `split_to`/`checked_split_to` and `split_off`. With `exhausted`, the content reads as empty
+/// whenever that method returns true, only `start` and `end` keep delegating.
+macro_rules! delegate_input {
+    (@iter $iter: ty) => { $iter };
+    (@iter $iter: ty, $exhausted: ident) => { core::iter::Take<$iter> };
+    ($wrapper: ident { front: $front: ident, back: $back: ident $(, exhausted: $exhausted: ident)? $(,)? }) => {
+        impl<I> $crate::input::Input for $wrapper<I>
+        where
+            I: $crate::input::Input,
+        {
+            type Item = I::Item;
+
+            type Error = I::Error;
+
+            type Iter = $crate::input::delegate_input!(@iter I::Iter $(, $exhausted)?);
+
+            type IterIndices = $crate::input::delegate_input!(@iter I::IterIndices $(, $exhausted)?);
+
+            #[inline]
+            fn len(&self) -> usize {
+                $(if self.$exhausted() {
+                    return 0;
+                })?
+
+                self.inner.len()
+            }
+
+            #[inline]
+            fn split_to(&mut self, at: usize) -> Self {
+                let head = self.inner.split_to(at);
+                self.$front(head)
+            }
+
+            #[inline]
+            fn checked_split_to(&mut self, at: usize) -> Option<Self> {
+                let head = self.inner.checked_split_to(at)?;
+                Some(self.$front(head))
+            }
+
+            #[inline]
+            fn split_off(&mut self, at: usize) -> Self {
+                let tail = self.inner.split_off(at);
+                self.$back(tail)
+            }
+
+            #[inline]
+            fn iter(&self) -> Self::Iter {
+                let iter = self.inner.iter();
+                $(let iter = iter.take(if self.$exhausted() { 0 } else { usize::MAX });)?
+                iter
+            }
+
+            #[inline]
+            fn iter_indices(&self) -> Self::IterIndices {
+                let iter = self.inner.iter_indices();
+                $(let iter = iter.take(if self.$exhausted() { 0 } else { usize::MAX });)?
+                iter
+            }
+
+            #[inline]
+            fn peek(&self) -> Option<Self::Item> {
+                $(if self.$exhausted() {
+                    return None;
+                })?
+
+                self.inner.peek()
+            }
+
+            #[inline]
+            fn with_offset(mut self, base: usize) -> Self {
+                self.inner = self.inner.with_offset(base);
+                self
+            }
+
+            #[inline]
+            fn start(&self) -> usize {
+                self.inner.start()
+            }
+
+            #[inline]
+            fn end(&self) -> usize {
+                self.inner.end()
+            }
+        }
+
+        impl<I> $crate::input::AsBytes for $wrapper<I>
+        where
+            I: $crate::input::Input + $crate::input::AsBytes,
+        {
+            #[inline]
+            fn as_bytes(&self) -> &[u8] {
+                $(if self.$exhausted() {
+                    return &[];
+                })?
+
+                self.inner.as_bytes()
+            }
+        }
+
+        impl<I> $crate::input::AsStr for $wrapper<I>
+        where
+            I: $crate::input::Input + $crate::input::AsStr,
+        {
+            #[inline]
+            fn as_str(&self) -> &str {
+                $(if self.$exhausted() {
+                    return "";
+                })?
+
+                self.inner.as_str()
+            }
+        }
+
+        impl<I, N> $crate::input::StartWith<N> for $wrapper<I>
+        where
+            I: $crate::input::Input + $crate::input::StartWith<N>,
+        {
+            #[inline]
+            fn starts_with(&self, needle: N) -> Option<usize> {
+                $(if self.$exhausted() {
+                    return None;
+                })?
+
+                self.inner.starts_with(needle)
+            }
+        }
+
+        impl<I, N> $crate::input::Find<N> for $wrapper<I>
+        where
+            I: $crate::input::Input + $crate::input::Find<N>,
+        {
+            #[inline]
+            fn find(&self, needle: N) -> Option<usize> {
+                $(if self.$exhausted() {
+                    return None;
+                })?
+
+                self.inner.find(needle)
+            }
+        }
+
+        impl<I, N> $crate::input::FindAll<N> for $wrapper<I>
+        where
+            I: $crate::input::Input + $crate::input::FindAll<N>,
+        {
+            #[inline]
+            fn find_all(&self, needle: N) -> alloc::vec::Vec<usize> {
+                $(if self.$exhausted() {
+                    return alloc::vec::Vec::new();
+                })?
+
+                self.inner.find_all(needle)
+            }
+        }
+
+        #[cfg(feature = "input")]
+        impl<I> $crate::bytes::BytesInput for $wrapper<I> where I: $crate::bytes::BytesInput {}
+
+        #[cfg(feature = "input")]
+        impl<I> $crate::chars::CharsInput for $wrapper<I> where I: $crate::chars::CharsInput {}
+    };
+}
+
+pub(crate) use delegate_input;
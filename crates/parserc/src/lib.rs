@@ -1,5 +1,17 @@
 //! Parse combinator framework for `rust` language.
+//!
+//! # `no_std`
+//!
+//! Disable the default `std` feature to build on `#![no_std]` targets, the crate still
+//! requires `alloc`: [`map_boxed`](Parser::map_boxed), [`BoxedParser`], [`split_on`], [`sep_end_by0`],
+//! [`sep_end_by1`], [`between`], [`FindAll`], [`FuelInput`], [`DepthInput`] and the `syntax`,
+//! `pratt` and `debug` modules allocate.
+//! Without `std`, `syntax::Map` only collects into a `BTreeMap`, and the `left_recursion`
+//! field option of the `Syntax` derive is unavailable.
 #![cfg_attr(docsrs, feature(doc_cfg))]
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate alloc;
 
 mod input;
 pub use input::*;
@@ -16,6 +28,31 @@ pub use parser::*;
 mod c;
 pub use c::*;
 
+mod fuel;
+pub use fuel::*;
+
+mod depth;
+pub use depth::*;
+
+mod lookbehind;
+pub use lookbehind::*;
+
+pub mod predicates;
+
 #[cfg(feature = "syntax")]
 #[cfg_attr(docsrs, doc(cfg(feature = "syntax")))]
 pub mod syntax;
+
+#[cfg(feature = "pratt")]
+#[cfg_attr(docsrs, doc(cfg(feature = "pratt")))]
+pub mod pratt;
+
+#[cfg(feature = "debug")]
+#[cfg_attr(docsrs, doc(cfg(feature = "debug")))]
+pub mod debug;
+
+/// Re-exports used by the code generated by `parserc-derive`, not public API.
+#[doc(hidden)]
+pub mod __private {
+    pub use alloc::string::String;
+}
@@ -0,0 +1,103 @@
+//! One item of lookbehind, for positional assertions like a word boundary.
+
+use core::fmt::Debug;
+
+use crate::input::{Input, delegate_input};
+
+/// An input that knows the item right before its start, see [`LookBehindInput`].
+pub trait LookBehind: Input {
+    /// Returns the item before the start of this input,
+    /// `None` at the start of the wrapped source.
+    fn prev_item(&self) -> Option<Self::Item>;
+}
+
+/// An [`Input`] wrapper that remembers the last item split off its front.
+///
+/// Inputs are forward-only slices, so e.g. a word boundary can't be checked on them;
+/// wrap the source with this type to implement [`LookBehind`].
+/// Each split scans the split part once for its last item.
+pub struct LookBehindInput<I>
+where
+    I: Input,
+{
+    inner: I,
+    prev: Option<I::Item>,
+}
+
+impl<I> LookBehindInput<I>
+where
+    I: Input,
+{
+    /// Wraps `inner`, the start of `inner` has no previous item.
+    pub fn new(inner: I) -> Self {
+        Self { inner, prev: None }
+    }
+
+    /// Returns the wrapped input.
+    pub fn into_inner(self) -> I {
+        self.inner
+    }
+
+    #[inline]
+    fn split_front(&mut self, head: I) -> Self {
+        let prev = self.prev;
+
+        if let Some(last) = head.iter().last() {
+            self.prev = Some(last);
+        }
+
+        Self { inner: head, prev }
+    }
+
+    #[inline]
+    fn split_back(&mut self, tail: I) -> Self {
+        let prev = self.inner.iter().last().or(self.prev);
+
+        Self { inner: tail, prev }
+    }
+}
+
+impl<I> LookBehind for LookBehindInput<I>
+where
+    I: Input,
+{
+    #[inline]
+    fn prev_item(&self) -> Option<Self::Item> {
+        self.prev
+    }
+}
+
+impl<I> Clone for LookBehindInput<I>
+where
+    I: Input + Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            inner: self.inner.clone(),
+            prev: self.prev,
+        }
+    }
+}
+
+impl<I> Debug for LookBehindInput<I>
+where
+    I: Input,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        self.inner.fmt(f)
+    }
+}
+
+impl<I> PartialEq for LookBehindInput<I>
+where
+    I: Input,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.inner == other.inner
+    }
+}
+
+delegate_input!(LookBehindInput {
+    front: split_front,
+    back: split_back,
+});
@@ -1,8 +1,13 @@
 //! Traits for parser combinators.
 
+use alloc::boxed::Box;
+use core::cell::RefCell;
+
 use crate::{
-    errors::{ControlFlow, ParseError},
-    input::Input,
+    errors::{ControlFlow, Deescalate, IntoErrorKind, Kind, ParseError},
+    fuel::{Fuel, WithFuel},
+    input::{Find, Input, Length},
+    span::Span,
 };
 
 /// A parsing combinator should implement this trait.
@@ -45,6 +50,103 @@ where
         MapErr(self, f)
     }
 
+    /// Creates a parser that replaces any error with the error of `kind`,
+    /// preserving its `ControlFlow` and span.
+    ///
+    /// ```
+    /// use parserc::{ControlFlow, Kind, Parser, Span, chars::TokenStream, next};
+    ///
+    /// assert_eq!(
+    ///     next('a')
+    ///         .map_err_kind(Kind::TakeWhile)
+    ///         .parse(&mut TokenStream::<Kind>::from("b")),
+    ///     Err(Kind::TakeWhile(ControlFlow::Recovable, Span::Range(0..1)))
+    /// );
+    /// ```
+    #[inline]
+    fn map_err_kind<K>(self, kind: K) -> impl Parser<I, Output = Self::Output>
+    where
+        K: IntoErrorKind<I::Error>,
+        Self: Sized,
+    {
+        self.map_err(move |err: I::Error| kind.into_error(err.control_flow(), err.to_span()))
+    }
+
+    /// Creates a parser that replaces any error with [`Kind::Syntax`] named `name`,
+    /// preserving its `ControlFlow` and span.
+    ///
+    /// ```
+    /// use parserc::{Kind, Parser, chars::TokenStream, next_if};
+    ///
+    /// let err = next_if(|c: char| c.is_ascii_digit())
+    ///     .label("number")
+    ///     .parse(&mut TokenStream::<Kind>::from("x"))
+    ///     .unwrap_err();
+    ///
+    /// assert_eq!(err.to_string(), "Error from parsing syntax `number`");
+    /// ```
+    #[inline]
+    fn label(self, name: &'static str) -> impl Parser<I, Output = Self::Output>
+    where
+        Self: Sized,
+    {
+        self.map_err_kind(name)
+    }
+
+    /// Like [`label`](Parser::label), but the span of the error starts at the position where
+    /// this parser started, so it covers the whole construct up to the failure point,
+    /// not just the failing token.
+    ///
+    /// ```
+    /// use parserc::{ControlFlow, Kind, Parser, Span, chars::TokenStream, keyword, next};
+    ///
+    /// let mut input = TokenStream::<Kind>::from("x = (1;");
+    /// keyword("x = ").parse(&mut input).unwrap();
+    ///
+    /// let err = (|input: &mut TokenStream<'static, Kind>| {
+    ///     next('(').parse(input)?;
+    ///     next('1').parse(input)?;
+    ///     next(')').parse(input)
+    /// })
+    /// .context_span("group")
+    /// .parse(&mut input);
+    ///
+    /// assert_eq!(err, Err(Kind::Syntax("group", ControlFlow::Recovable, Span::Range(4..7))));
+    /// ```
+    #[inline]
+    fn context_span(self, label: &'static str) -> impl Parser<I, Output = Self::Output>
+    where
+        Self: Sized,
+    {
+        ContextSpan(self, label)
+    }
+
+    /// Creates a parser that fails with a [`fatal`](ControlFlow::Fatal) [`Kind::NoProgress`] error
+    /// if this parser succeeds without consuming input.
+    ///
+    /// Wrap the element parser of a repetition with it, so an element that may match nothing
+    /// stops the loop with an error instead of spinning forever.
+    /// The built-in repetitions, e.g. [`count`](crate::count), [`between`](crate::between),
+    /// `Vec<T>` and [`Punctuated`](crate::syntax::Punctuated), already apply this check.
+    ///
+    /// ```
+    /// use parserc::{ControlFlow, Kind, Parser, Span, chars::TokenStream, take_while};
+    ///
+    /// assert_eq!(
+    ///     take_while(|c: char| c == 'a')
+    ///         .ensure_progress()
+    ///         .parse(&mut TokenStream::<Kind>::from("b")),
+    ///     Err(Kind::NoProgress(ControlFlow::Fatal, Span::Range(0..0)))
+    /// );
+    /// ```
+    #[inline]
+    fn ensure_progress(self) -> impl Parser<I, Output = Self::Output>
+    where
+        Self: Sized,
+    {
+        EnsureProgress(self)
+    }
+
     /// Creates a parser that convert all `non-fatal` error into [`fatal`](ControlFlow::Fatal) error.
     #[inline]
     fn fatal(self) -> impl Parser<I, Output = Self::Output>
@@ -54,13 +156,74 @@ where
         Fatal(self)
     }
 
+    /// Creates a new parser that returns `Self::Output::default()` on `non-fatal` error,
+    /// restoring the input. Fatal errors are propagated.
+    #[inline]
+    fn or_default(self) -> impl Parser<I, Output = Self::Output>
+    where
+        I: Clone,
+        Self::Output: Default,
+        Self: Sized,
+    {
+        self.ok().map(Option::unwrap_or_default)
+    }
+
+    /// Creates a new parser that returns `value` on `non-fatal` error,
+    /// restoring the input. Fatal errors are propagated.
+    ///
+    /// ```
+    /// use parserc::{Kind, Parser, chars::TokenStream, next};
+    ///
+    /// let sign = || next('-').map(|_| -1).or_value(1);
+    ///
+    /// assert_eq!(sign().parse(&mut TokenStream::<Kind>::from("-2")), Ok(-1));
+    /// assert_eq!(sign().parse(&mut TokenStream::<Kind>::from("2")), Ok(1));
+    /// ```
+    #[inline]
+    fn or_value(self, value: Self::Output) -> impl Parser<I, Output = Self::Output>
+    where
+        I: Clone,
+        Self: Sized,
+    {
+        self.ok().map(move |output| output.unwrap_or(value))
+    }
+
+    /// Creates a parser that downgrades [`fatal`](ControlFlow::Fatal) errors to
+    /// [`recoverable`](ControlFlow::Recovable) errors at the same span, the inverse of [`fatal`](Parser::fatal).
+    ///
+    /// A fatal error usually means that the grammar has committed to an alternative,
+    /// this parser discards that commitment: enclosing `or`/`ok` combinators will backtrack
+    /// and try other alternatives, and the more precise fatal error may be replaced by a vaguer one.
+    /// Use it only for sub-grammars that are genuinely optional.
+    #[inline]
+    fn recoverable(self) -> impl Parser<I, Output = Self::Output>
+    where
+        Self: Sized,
+        I::Error: Deescalate,
+    {
+        self.map_err(Deescalate::deescalate)
+    }
+
     /// Map output into `Box<Self::Output>`, this func is short for code `Parser::map(|v|Box::new(v))`
     #[inline]
+    fn map_boxed(self) -> impl Parser<I, Output = Box<Self::Output>>
+    where
+        Self: Sized,
+    {
+        self.map(Box::new)
+    }
+
+    /// Same as [`map_boxed`](Parser::map_boxed).
+    ///
+    /// Despite its name, this boxes the output, not the parser;
+    /// use [`erase`](Parser::erase) to box the parser.
+    #[deprecated(note = "boxes the output, use `map_boxed`; or use `erase` to box the parser")]
+    #[inline]
     fn boxed(self) -> impl Parser<I, Output = Box<Self::Output>>
     where
         Self: Sized,
     {
-        self.map(|v| Box::new(v))
+        self.map_boxed()
     }
 
     /// Executre another `Parser` if this one returns a `non-fatal` error.
@@ -73,6 +236,178 @@ where
     {
         Or(self, parser)
     }
+
+    /// Runs this parser, then requires the input to be fully consumed,
+    /// otherwise returns a recoverable [`Kind::Eof`] error with the span of the leftover input.
+    ///
+    /// ```
+    /// use parserc::{ControlFlow, Kind, Parser, Span, chars::TokenStream, next};
+    ///
+    /// let mut input = TokenStream::<Kind>::from("a");
+    /// assert!(next('a').terminated_by_eof().parse(&mut input).is_ok());
+    ///
+    /// let mut input = TokenStream::<Kind>::from("ab");
+    /// assert_eq!(
+    ///     next('a').terminated_by_eof().parse(&mut input),
+    ///     Err(Kind::Eof(ControlFlow::Recovable, Span::Range(1..2)))
+    /// );
+    /// ```
+    #[inline]
+    fn terminated_by_eof(self) -> impl Parser<I, Output = Self::Output>
+    where
+        Self: Sized,
+    {
+        TerminatedByEof(self)
+    }
+
+    /// Runs this parser, then asserts that `parser` does not match at the new position.
+    ///
+    /// `parser` never consumes input. If it matches, the input is restored and
+    /// a recoverable [`Kind::NotFollowedBy`] error is returned.
+    #[inline]
+    fn not_followed_by<R>(self, parser: R) -> impl Parser<I, Output = Self::Output>
+    where
+        I: Clone,
+        R: Parser<I>,
+        Self: Sized,
+    {
+        NotFollowedBy(self, parser)
+    }
+
+    /// Runs this parser to get an input slice, then runs `inner` against that slice only,
+    /// returning the output of `inner`.
+    ///
+    /// `inner` must consume the whole slice, otherwise a [`Kind::Eof`] error covering
+    /// the leftover of the slice is returned, see [`terminated_by_eof`](Parser::terminated_by_eof).
+    /// The slice keeps its position, so the spans are positions in the outer input.
+    ///
+    /// ```
+    /// use parserc::{Kind, Parser, chars::TokenStream, next, take_until, take_while};
+    ///
+    /// let mut input = TokenStream::<Kind>::from("ab}c");
+    ///
+    /// let body = take_until("}")
+    ///     .and_parse(take_while(|c: char| c.is_ascii_alphabetic()))
+    ///     .parse(&mut input);
+    ///
+    /// assert_eq!(body, Ok(TokenStream::from("ab")));
+    /// assert_eq!(input, TokenStream::from((2, "}c")));
+    /// ```
+    #[inline]
+    fn and_parse<P>(self, inner: P) -> impl Parser<I, Output = P::Output>
+    where
+        Self: Parser<I, Output = I> + Sized,
+        P: Parser<I>,
+    {
+        AndParse(self, inner)
+    }
+
+    /// Runs this parser, then interprets its output with `f`.
+    ///
+    /// If `f` returns `None`, the input is restored and a recoverable [`Kind::Verify`] error
+    /// with the span of the rejected match is returned.
+    ///
+    /// ```
+    /// use parserc::{ControlFlow, Kind, Parser, Span, chars::TokenStream, take_while};
+    ///
+    /// let mut input = TokenStream::<Kind>::from("7f");
+    ///
+    /// assert_eq!(
+    ///     take_while(|c: char| c.is_ascii_hexdigit())
+    ///         .verify_map(|digits: TokenStream<Kind>| {
+    ///             u8::from_str_radix(digits.value, 16).ok().filter(|c| c.is_ascii())
+    ///         })
+    ///         .parse(&mut input),
+    ///     Ok(0x7f)
+    /// );
+    /// ```
+    #[inline]
+    fn verify_map<F, O>(self, f: F) -> impl Parser<I, Output = O>
+    where
+        I: Clone,
+        F: FnOnce(Self::Output) -> Option<O>,
+        Self: Sized,
+    {
+        VerifyMap(self, f)
+    }
+
+    /// Creates a parser that resyncs the input after a `non-fatal` error of this parser:
+    /// the input is restored, then advanced past the next occurrence of `sync`,
+    /// or to the end of the input if `sync` never occurs.
+    ///
+    /// Returns the error as `Ok(Err(err))`, so the caller can report it and keep parsing.
+    /// Fatal errors are propagated; to resync after them too, use
+    /// [`recoverable`](Parser::recoverable) first.
+    ///
+    /// ```
+    /// use parserc::{Kind, Parser, chars::TokenStream, keyword};
+    ///
+    /// let mut input = TokenStream::<Kind>::from("let x = ; y;");
+    ///
+    /// let result = keyword("y;").recover_to(";").parse(&mut input).unwrap();
+    ///
+    /// assert!(result.is_err());
+    /// assert_eq!(input, TokenStream::from((9, " y;")));
+    /// ```
+    #[inline]
+    fn recover_to<S>(self, sync: S) -> impl Parser<I, Output = Result<Self::Output, I::Error>>
+    where
+        I: Clone + Find<S>,
+        S: Length,
+        Self: Sized,
+    {
+        RecoverTo(self, sync)
+    }
+
+    /// Runs this parser with a budget of `fuel` input clones, see [`FuelInput`](crate::FuelInput).
+    ///
+    /// Each backtracking attempt made by [`ok`](Parser::ok) or [`or`](Parser::or) burns one unit.
+    /// If the fuel runs out, returns a fatal [`Kind::FuelExhausted`] error,
+    /// whatever the result of this parser.
+    ///
+    /// The budget is capped by the fuel left in the input, and the fuel spent is charged to it,
+    /// so a nested or repeated `with_fuel` never raises the total budget of the input.
+    #[inline]
+    fn with_fuel(self, fuel: usize) -> impl Parser<I, Output = Self::Output>
+    where
+        I: Fuel,
+        Self: Sized,
+    {
+        WithFuel(self, fuel)
+    }
+
+    /// Records the attempts of this parser into `trace` under `label`, see [`debug`](crate::debug).
+    #[cfg(feature = "debug")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "debug")))]
+    #[inline]
+    fn debug(
+        self,
+        label: &'static str,
+        trace: &crate::debug::ParseTrace,
+    ) -> crate::debug::DebugParser<Self>
+    where
+        Self: Sized,
+    {
+        crate::debug::DebugParser {
+            parser: self,
+            label,
+            trace: trace.clone(),
+        }
+    }
+
+    /// Erases the concrete type of this parser, see [`BoxedParser`].
+    ///
+    /// The result is a named type that can be stored in struct fields. The parser is cloned
+    /// for each parse, so the boxed parser can run more than once. Combinator chains are
+    /// usually not `Clone`, wrap them in a closure, e.g.
+    /// `(|input: &mut I| keyword("let").parse(input)).erase()`.
+    #[inline]
+    fn erase<'a>(self) -> BoxedParser<'a, I, Self::Output>
+    where
+        Self: Clone + Sized + 'a,
+    {
+        BoxedParser::new(move |input: &mut I| self.clone().parse(input))
+    }
 }
 
 /// Implement [`Parser`] for all `FnOnce(I) -> Result<O, I, E>`
@@ -164,6 +499,170 @@ where
     }
 }
 
+struct TerminatedByEof<P>(P);
+
+impl<P, I> Parser<I> for TerminatedByEof<P>
+where
+    I: Input,
+    P: Parser<I>,
+{
+    type Output = P::Output;
+
+    #[inline]
+    fn parse(self, input: &mut I) -> Result<Self::Output, I::Error> {
+        let output = self.0.parse(input)?;
+
+        if input.is_empty() {
+            Ok(output)
+        } else {
+            Err(Kind::Eof(ControlFlow::Recovable, input.to_span()).into())
+        }
+    }
+}
+
+struct NotFollowedBy<L, R>(L, R);
+
+impl<L, R, I> Parser<I> for NotFollowedBy<L, R>
+where
+    I: Input + Clone,
+    L: Parser<I>,
+    R: Parser<I>,
+{
+    type Output = L::Output;
+
+    #[inline]
+    fn parse(self, input: &mut I) -> Result<Self::Output, I::Error> {
+        let start = input.clone();
+        let output = self.0.parse(input)?;
+
+        if self.1.ok().parse(&mut input.clone())?.is_some() {
+            let span = input.to_span_at(1);
+            *input = start;
+            return Err(Kind::NotFollowedBy(ControlFlow::Recovable, span).into());
+        }
+
+        Ok(output)
+    }
+}
+
+struct AndParse<L, R>(L, R);
+
+impl<L, R, I> Parser<I> for AndParse<L, R>
+where
+    I: Input,
+    L: Parser<I, Output = I>,
+    R: Parser<I>,
+{
+    type Output = R::Output;
+
+    #[inline]
+    fn parse(self, input: &mut I) -> Result<Self::Output, I::Error> {
+        let mut slice = self.0.parse(input)?;
+
+        self.1.terminated_by_eof().parse(&mut slice)
+    }
+}
+
+struct VerifyMap<P, F>(P, F);
+
+impl<P, F, I, O> Parser<I> for VerifyMap<P, F>
+where
+    I: Input + Clone,
+    P: Parser<I>,
+    F: FnOnce(P::Output) -> Option<O>,
+{
+    type Output = O;
+
+    #[inline]
+    fn parse(self, input: &mut I) -> Result<Self::Output, I::Error> {
+        let start = input.clone();
+        let output = self.0.parse(input)?;
+
+        let Some(output) = (self.1)(output) else {
+            let span = start.to_span_at(start.len() - input.len());
+            *input = start;
+            return Err(Kind::Verify(ControlFlow::Recovable, span).into());
+        };
+
+        Ok(output)
+    }
+}
+
+struct RecoverTo<P, S>(P, S);
+
+impl<P, S, I> Parser<I> for RecoverTo<P, S>
+where
+    I: Input + Clone + Find<S>,
+    P: Parser<I>,
+    S: Length,
+{
+    type Output = Result<P::Output, I::Error>;
+
+    #[inline]
+    fn parse(self, input: &mut I) -> Result<Self::Output, I::Error> {
+        let start = input.clone();
+
+        match self.0.parse(input) {
+            Ok(output) => Ok(Ok(output)),
+            Err(err) if err.control_flow() == ControlFlow::Fatal => Err(err),
+            Err(err) => {
+                *input = start;
+
+                let len = self.1.len();
+                let offset = input
+                    .find(self.1)
+                    .map_or(input.len(), |offset| offset + len);
+
+                input.split_to(offset);
+
+                Ok(Err(err))
+            }
+        }
+    }
+}
+
+struct ContextSpan<P>(P, &'static str);
+
+impl<P, I> Parser<I> for ContextSpan<P>
+where
+    I: Input,
+    P: Parser<I>,
+{
+    type Output = P::Output;
+
+    #[inline]
+    fn parse(self, input: &mut I) -> Result<Self::Output, I::Error> {
+        let start = Span::point(input.start());
+
+        self.0.parse(input).map_err(|err| {
+            self.1
+                .into_error(err.control_flow(), start.union(&err.to_span()))
+        })
+    }
+}
+
+struct EnsureProgress<P>(P);
+
+impl<P, I> Parser<I> for EnsureProgress<P>
+where
+    I: Input,
+    P: Parser<I>,
+{
+    type Output = P::Output;
+
+    #[inline]
+    fn parse(self, input: &mut I) -> Result<Self::Output, I::Error> {
+        let start = input.start();
+        let output = self.0.parse(input)?;
+
+        if input.start() == start {
+            return Err(Kind::NoProgress(ControlFlow::Fatal, input.to_span_at(0)).into());
+        }
+
+        Ok(output)
+    }
+}
+
 struct Or<L, R>(L, R);
 
 impl<L, R, I, O> Parser<I> for Or<L, R>
@@ -185,3 +684,124 @@ where
         self.1.parse(input)
     }
 }
+
+type BoxedParseFn<'a, I, O> = Box<dyn FnMut(&mut I) -> Result<O, <I as Input>::Error> + 'a>;
+
+/// A type-erased, reusable parser.
+///
+/// Unlike the `impl Parser` values returned by the combinators, a `BoxedParser` has a nameable
+/// type, so parsers of different shapes can be stored together, e.g. in a `Vec`, and chosen at
+/// runtime. This costs a heap allocation and a dynamic call per parse, and prevents inlining,
+/// so prefer the zero-cost `impl Parser` style unless the grammar is composed dynamically.
+///
+/// A `&mut BoxedParser` is also a [`Parser`], which allows running it more than once.
+pub struct BoxedParser<'a, I, O>
+where
+    I: Input,
+{
+    parser: BoxedParseFn<'a, I, O>,
+}
+
+impl<'a, I, O> BoxedParser<'a, I, O>
+where
+    I: Input,
+{
+    /// Create a new boxed parser from a reusable parse function.
+    #[inline]
+    pub fn new<F>(f: F) -> Self
+    where
+        F: FnMut(&mut I) -> Result<O, I::Error> + 'a,
+    {
+        Self {
+            parser: Box::new(f),
+        }
+    }
+}
+
+impl<'a, I, O> Parser<I> for BoxedParser<'a, I, O>
+where
+    I: Input,
+{
+    type Output = O;
+
+    #[inline]
+    fn parse(mut self, input: &mut I) -> Result<Self::Output, I::Error> {
+        (self.parser)(input)
+    }
+}
+
+impl<'a, 'b, I, O> Parser<I> for &'b mut BoxedParser<'a, I, O>
+where
+    I: Input,
+{
+    type Output = O;
+
+    #[inline]
+    fn parse(self, input: &mut I) -> Result<Self::Output, I::Error> {
+        (self.parser)(input)
+    }
+}
+
+/// An adapter that lets one `FnMut(&mut I) -> Result<O, I::Error>` closure run many times.
+///
+/// A closure is a [`Parser`] only by value, and a repetition combinator's factory can't hand out
+/// `&mut` borrows of a captured closure. A `&FnMutParser` is a `Copy` [`Parser`] instead,
+/// so `|| &parser` is a valid factory for e.g. [`count`](crate::count) or [`between`](crate::between).
+///
+/// The closure is mutably borrowed for the duration of each parse, so calling the same
+/// `FnMutParser` again from inside the closure panics.
+///
+/// ```
+/// use parserc::{FnMutParser, Kind, Parser, chars::TokenStream, count, next};
+///
+/// let mut calls = 0;
+/// let parser = FnMutParser::new(|input: &mut TokenStream<'static, Kind>| {
+///     calls += 1;
+///     next('a').parse(input)
+/// });
+///
+/// assert_eq!(count(|| &parser).parse(&mut TokenStream::from("aab")), Ok(2));
+///
+/// assert_eq!(calls, 3);
+/// ```
+pub struct FnMutParser<F>(RefCell<F>);
+
+impl<F> FnMutParser<F> {
+    /// Wraps a reusable parse function.
+    #[inline]
+    pub fn new(f: F) -> Self {
+        Self(RefCell::new(f))
+    }
+
+    /// Returns the wrapped parse function.
+    #[inline]
+    pub fn into_inner(self) -> F {
+        self.0.into_inner()
+    }
+}
+
+impl<F, I, O> Parser<I> for FnMutParser<F>
+where
+    I: Input,
+    F: FnMut(&mut I) -> Result<O, I::Error>,
+{
+    type Output = O;
+
+    #[inline]
+    fn parse(self, input: &mut I) -> Result<Self::Output, I::Error> {
+        (self.0.into_inner())(input)
+    }
+}
+
+impl<F, I, O> Parser<I> for &FnMutParser<F>
+where
+    I: Input,
+    F: FnMut(&mut I) -> Result<O, I::Error>,
+{
+    type Output = O;
+
+    #[inline]
+    fn parse(self, input: &mut I) -> Result<Self::Output, I::Error> {
+        (self.0.borrow_mut())(input)
+    }
+}
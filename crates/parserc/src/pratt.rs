@@ -0,0 +1,247 @@
+//! Precedence climbing parser for expression grammars.
+//!
+//! Register the operand parser and the prefix, infix and postfix operators on a [`Pratt`],
+//! then use it as a [`Parser`]:
+//!
+//! ```
+//! use parserc::{Kind, Parser, chars::TokenStream, next, next_if, pratt::{Assoc, Pratt}};
+//!
+//! let mut input = TokenStream::<Kind>::from("1+2*3");
+//!
+//! let value = Pratt::new(|| next_if(|c: char| c.is_ascii_digit()).map(|d: TokenStream<Kind>| {
+//!         d.value.parse::<i64>().unwrap()
+//!     }))
+//!     .infix(|| next('+'), 1, Assoc::Left, |lhs, _, rhs| lhs + rhs)
+//!     .infix(|| next('*'), 2, Assoc::Left, |lhs, _, rhs| lhs * rhs)
+//!     .parse(&mut input);
+//!
+//! assert_eq!(value, Ok(7));
+//! ```
+
+use alloc::{boxed::Box, rc::Rc, vec, vec::Vec};
+
+use crate::{BoxedParser, ControlFlow, Kind, input::Input, parser::Parser};
+
+/// Associativity of an infix operator.
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Clone, Copy, Hash)]
+pub enum Assoc {
+    /// `a op b op c` is parsed as `(a op b) op c`.
+    Left,
+    /// `a op b op c` is parsed as `a op (b op c)`.
+    Right,
+}
+
+type Unary<'a, O> = Box<dyn FnOnce(O) -> O + 'a>;
+type Binary<'a, O> = Box<dyn FnOnce(O, O) -> O + 'a>;
+
+struct Op<'a, I, B>
+where
+    I: Input,
+{
+    token: BoxedParser<'a, I, B>,
+    prec: u32,
+    assoc: Assoc,
+}
+
+/// A precedence climbing parser, see the [module level documentation](self).
+///
+/// Operators are tried in registration order, a higher `prec` binds tighter.
+pub struct Pratt<'a, I, O>
+where
+    I: Input,
+{
+    operand: BoxedParser<'a, I, O>,
+    prefix: Vec<Op<'a, I, Unary<'a, O>>>,
+    infix: Vec<Op<'a, I, Binary<'a, O>>>,
+    postfix: Vec<Op<'a, I, Unary<'a, O>>>,
+}
+
+impl<'a, I, O> Pratt<'a, I, O>
+where
+    I: Input + Clone + 'a,
+    O: 'a,
+{
+    /// Creates a new parser with the factory of the operand parser.
+    pub fn new<F, P>(mut operand: F) -> Self
+    where
+        F: FnMut() -> P + 'a,
+        P: Parser<I, Output = O>,
+    {
+        Self {
+            operand: BoxedParser::new(move |input: &mut I| operand().parse(input)),
+            prefix: vec![],
+            infix: vec![],
+            postfix: vec![],
+        }
+    }
+
+    /// Registers a prefix operator, `build` combines the operator token with its operand.
+    pub fn prefix<F, P, B>(mut self, mut token: F, prec: u32, build: B) -> Self
+    where
+        F: FnMut() -> P + 'a,
+        P: Parser<I>,
+        P::Output: 'a,
+        B: Fn(P::Output, O) -> O + 'a,
+    {
+        let build = Rc::new(build);
+
+        self.prefix.push(Op {
+            token: BoxedParser::new(move |input: &mut I| {
+                let token = token().parse(input)?;
+                let build = build.clone();
+                Ok(Box::new(move |operand| build(token, operand)) as Unary<'a, O>)
+            }),
+            prec,
+            assoc: Assoc::Right,
+        });
+
+        self
+    }
+
+    /// Registers an infix operator, `build` combines the operator token with both operands.
+    pub fn infix<F, P, B>(mut self, mut token: F, prec: u32, assoc: Assoc, build: B) -> Self
+    where
+        F: FnMut() -> P + 'a,
+        P: Parser<I>,
+        P::Output: 'a,
+        B: Fn(O, P::Output, O) -> O + 'a,
+    {
+        let build = Rc::new(build);
+
+        self.infix.push(Op {
+            token: BoxedParser::new(move |input: &mut I| {
+                let token = token().parse(input)?;
+                let build = build.clone();
+                Ok(Box::new(move |lhs, rhs| build(lhs, token, rhs)) as Binary<'a, O>)
+            }),
+            prec,
+            assoc,
+        });
+
+        self
+    }
+
+    /// Registers a postfix operator, `build` combines the operand with the operator token.
+    pub fn postfix<F, P, B>(mut self, mut token: F, prec: u32, build: B) -> Self
+    where
+        F: FnMut() -> P + 'a,
+        P: Parser<I>,
+        P::Output: 'a,
+        B: Fn(O, P::Output) -> O + 'a,
+    {
+        let build = Rc::new(build);
+
+        self.postfix.push(Op {
+            token: BoxedParser::new(move |input: &mut I| {
+                let token = token().parse(input)?;
+                let build = build.clone();
+                Ok(Box::new(move |operand| build(operand, token)) as Unary<'a, O>)
+            }),
+            prec,
+            assoc: Assoc::Left,
+        });
+
+        self
+    }
+
+    /// Parses an operand with its prefix operators.
+    fn parse_unary(&mut self, input: &mut I) -> Result<O, I::Error> {
+        match Self::match_op(&mut self.prefix, input, 0)? {
+            Some((prec, _, build)) => Ok(build(self.parse_prec(input, prec)?)),
+            None => (&mut self.operand).parse(input),
+        }
+    }
+
+    fn parse_prec(&mut self, input: &mut I, min_prec: u32) -> Result<O, I::Error> {
+        let mut lhs = self.parse_unary(input)?;
+
+        loop {
+            let start = input.start();
+
+            if let Some((_, _, build)) = Self::match_op(&mut self.postfix, input, min_prec)? {
+                lhs = build(lhs);
+                Self::ensure_progress(input, start)?;
+                continue;
+            }
+
+            let Some((prec, assoc, build)) = Self::match_op(&mut self.infix, input, min_prec)?
+            else {
+                return Ok(lhs);
+            };
+
+            let rhs = match assoc {
+                Assoc::Left => match prec.checked_add(1) {
+                    Some(prec) => self.parse_prec(input, prec)?,
+                    // no operator binds tighter than `u32::MAX`.
+                    None => self.parse_unary(input)?,
+                },
+                Assoc::Right => self.parse_prec(input, prec)?,
+            };
+
+            lhs = build(lhs, rhs);
+            Self::ensure_progress(input, start)?;
+        }
+    }
+
+    /// Fails with a fatal [`Kind::NoProgress`] error if an operator loop iteration
+    /// starting at `start` consumed nothing, e.g. a zero-width postfix operator.
+    #[inline]
+    fn ensure_progress(input: &I, start: usize) -> Result<(), I::Error> {
+        if input.start() == start {
+            return Err(Kind::NoProgress(ControlFlow::Fatal, input.to_span_at(0)).into());
+        }
+
+        Ok(())
+    }
+
+    /// Returns the first operator that matches, if its precedence is at least `min_prec`.
+    fn match_op<B>(
+        ops: &mut [Op<'a, I, B>],
+        input: &mut I,
+        min_prec: u32,
+    ) -> Result<Option<(u32, Assoc, B)>, I::Error> {
+        for op in ops {
+            let mut try_input = input.clone();
+
+            let Some(build) = (&mut op.token).ok().parse(&mut try_input)? else {
+                continue;
+            };
+
+            if op.prec < min_prec {
+                return Ok(None);
+            }
+
+            *input = try_input;
+
+            return Ok(Some((op.prec, op.assoc, build)));
+        }
+
+        Ok(None)
+    }
+}
+
+impl<'a, I, O> Parser<I> for Pratt<'a, I, O>
+where
+    I: Input + Clone + 'a,
+    O: 'a,
+{
+    type Output = O;
+
+    #[inline]
+    fn parse(mut self, input: &mut I) -> Result<Self::Output, I::Error> {
+        self.parse_prec(input, 0)
+    }
+}
+
+impl<'a, I, O> Parser<I> for &mut Pratt<'a, I, O>
+where
+    I: Input + Clone + 'a,
+    O: 'a,
+{
+    type Output = O;
+
+    #[inline]
+    fn parse(self, input: &mut I) -> Result<Self::Output, I::Error> {
+        self.parse_prec(input, 0)
+    }
+}
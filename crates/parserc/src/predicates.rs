@@ -0,0 +1,57 @@
+//! Reusable char-class predicates, to pass to [`next_if`](crate::next_if),
+//! [`take_while`](crate::take_while) and friends.
+//!
+//! ```
+//! use parserc::{Kind, Parser, chars::TokenStream, next_if, predicates, take_while};
+//!
+//! let mut input = TokenStream::<Kind>::from("_x1 = 2");
+//!
+//! next_if(predicates::ident_start).parse(&mut input).unwrap();
+//!
+//! assert_eq!(
+//!     take_while(predicates::ident_continue).parse(&mut input),
+//!     Ok(TokenStream::from((1, "x1")))
+//! );
+//! ```
+
+/// Returns true if `c` can start an identifier: `_` or a `XID_Start` char.
+#[inline]
+pub fn ident_start(c: char) -> bool {
+    c == '_' || unicode_ident::is_xid_start(c)
+}
+
+/// Returns true if `c` can continue an identifier: a `XID_Continue` char, which includes `_`.
+#[inline]
+pub fn ident_continue(c: char) -> bool {
+    unicode_ident::is_xid_continue(c)
+}
+
+/// Returns true if `c` is an ASCII decimal digit `0-9`.
+#[inline]
+pub fn ascii_digit(c: char) -> bool {
+    c.is_ascii_digit()
+}
+
+/// Returns true if `c` is an ASCII hexadecimal digit `0-9`, `a-f` or `A-F`.
+#[inline]
+pub fn hex_digit(c: char) -> bool {
+    c.is_ascii_hexdigit()
+}
+
+/// Returns true if `c` has the Unicode `White_Space` property, see [`char::is_whitespace`].
+#[inline]
+pub fn whitespace(c: char) -> bool {
+    c.is_whitespace()
+}
+
+/// Returns true if `c` is an ASCII letter `a-z` or `A-Z`.
+#[inline]
+pub fn alpha(c: char) -> bool {
+    c.is_ascii_alphabetic()
+}
+
+/// Returns true if `c` is an ASCII letter or digit.
+#[inline]
+pub fn alnum(c: char) -> bool {
+    c.is_ascii_alphanumeric()
+}
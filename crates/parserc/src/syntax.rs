@@ -1,9 +1,21 @@
 //! Abstract sytax tree support.
 
-use std::{fmt::Debug, marker::PhantomData};
-
-use crate::{ControlFlow, Kind, Span, next};
-use crate::{input::Input, parser::Parser};
+use alloc::{boxed::Box, collections::BTreeMap, rc::Rc, string::String, vec, vec::Vec};
+use core::{
+    cell::RefCell,
+    fmt::Debug,
+    marker::PhantomData,
+    ops::{Deref, Range, RangeInclusive},
+};
+#[cfg(feature = "std")]
+use std::collections::HashMap;
+
+use crate::{ControlFlow, Expected, Found, Kind, Span, next};
+use crate::{
+    input::{Input, Item},
+    lookbehind::LookBehind,
+    parser::Parser,
+};
 
 /// An extension trait to help syntax struct parsing.
 pub trait SyntaxInput: Input {
@@ -20,6 +32,32 @@ pub trait SyntaxInput: Input {
 
 impl<I> SyntaxInput for I where I: Input {}
 
+/// Parses a `Syntax` type from the front of `source`,
+/// returning it with the number of bytes consumed.
+///
+/// The trailing content is left to the caller, e.g. another tokenizer.
+///
+/// ```
+/// use parserc::{Kind, chars::TokenStream, syntax::{Char, parse_partial}};
+///
+/// let (comma, consumed) = parse_partial::<Char<TokenStream<Kind>, ','>, _>(", b").unwrap();
+///
+/// assert_eq!(comma.0.value, ",");
+/// assert_eq!(consumed, 1);
+/// ```
+#[cfg(feature = "input")]
+pub fn parse_partial<'a, S, E>(source: &'a str) -> Result<(S, usize), E>
+where
+    S: Syntax<crate::chars::TokenStream<'a, E>>,
+    E: crate::ParseError + Clone,
+{
+    let mut input = crate::chars::TokenStream::from(source);
+
+    let value = S::parse(&mut input)?;
+
+    Ok((value, input.start()))
+}
+
 /// A syntax tree struct/enum should implment this trait
 pub trait Syntax<I>: Sized
 where
@@ -35,6 +73,54 @@ where
     fn into_parser() -> impl Parser<I, Output = Self> {
         SyntaxParser(Default::default(), Default::default())
     }
+
+    /// Parses a whole `source` string, the one-shot entry point parallel to
+    /// [`FromStr`](std::str::FromStr).
+    ///
+    /// Leftover input is reported as a recoverable [`Kind::Eof`] error,
+    /// see [`terminated_by_eof`](Parser::terminated_by_eof).
+    ///
+    /// ```
+    /// use parserc::{Kind, chars::TokenStream, syntax::{Char, Syntax}};
+    ///
+    /// let comma: Char<TokenStream<Kind>, ','> = Char::parse_from_str(",").unwrap();
+    ///
+    /// assert_eq!(comma.0.value, ",");
+    /// ```
+    #[inline]
+    fn parse_from_str<'a>(source: &'a str) -> Result<Self, I::Error>
+    where
+        I: From<&'a str>,
+    {
+        Self::into_parser()
+            .terminated_by_eof()
+            .parse(&mut I::from(source))
+    }
+}
+
+/// A syntax tree node that can reconstruct its source code.
+///
+/// Derive it along with [`Syntax`] by the item option `#[parserc(display)]`,
+/// which also implements [`Display`](std::fmt::Display) with the reconstructed source.
+pub trait ToSource {
+    /// Appends the source code of this node to `out`.
+    fn to_source(&self, out: &mut String);
+}
+
+#[cfg(feature = "input")]
+impl<E> ToSource for crate::chars::TokenStream<'_, E> {
+    #[inline]
+    fn to_source(&self, out: &mut String) {
+        out.push_str(crate::AsStr::as_str(self));
+    }
+}
+
+#[cfg(feature = "input")]
+impl<E> ToSource for crate::bytes::TokenStream<'_, E> {
+    #[inline]
+    fn to_source(&self, out: &mut String) {
+        out.push_str(crate::AsStr::as_str(self));
+    }
 }
 
 struct SyntaxParser<S, T>(PhantomData<S>, PhantomData<T>);
@@ -58,7 +144,7 @@ where
 {
     #[inline]
     fn parse(_input: &mut I) -> Result<Self, I::Error> {
-        Ok(Self::default())
+        Ok(PhantomData)
     }
 
     #[inline]
@@ -67,6 +153,130 @@ where
     }
 }
 
+impl<T> ToSource for PhantomData<T> {
+    #[inline]
+    fn to_source(&self, _out: &mut String) {}
+}
+
+/// A zero-width syntax node that matches only at the end of the input, consuming nothing.
+///
+/// Holds the empty input slice at the matched position, so its span is that position.
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Eof<I>(pub I);
+
+impl<I> Syntax<I> for Eof<I>
+where
+    I: Input,
+{
+    #[inline]
+    fn parse(input: &mut I) -> Result<Self, I::Error> {
+        if !input.is_empty() {
+            return Err(Kind::Eof(ControlFlow::Recovable, input.to_span()).into());
+        }
+
+        Ok(Self(input.split_to(0)))
+    }
+
+    #[inline]
+    fn to_span(&self) -> Span {
+        self.0.to_span()
+    }
+}
+
+impl<I> ToSource for Eof<I> {
+    #[inline]
+    fn to_source(&self, _out: &mut String) {}
+}
+
+#[inline]
+fn is_word_item<T>(item: Option<T>) -> bool
+where
+    T: Item,
+{
+    item.and_then(|item| item.to_char())
+        .is_some_and(|c| c.is_alphanumeric() || c == '_')
+}
+
+/// A zero-width syntax node that matches only between a word char and a non-word char,
+/// like regex `\b`, consuming nothing.
+///
+/// Word chars are alphanumeric chars and `_`; the start and the end of the input count as non-word.
+/// The input must know its previous item, e.g. a [`LookBehindInput`](crate::LookBehindInput).
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct WordBoundary<I>(pub I);
+
+impl<I> Syntax<I> for WordBoundary<I>
+where
+    I: LookBehind,
+{
+    #[inline]
+    fn parse(input: &mut I) -> Result<Self, I::Error> {
+        if is_word_item(input.prev_item()) == is_word_item(input.peek()) {
+            return Err(Kind::Expected {
+                what: Expected::Label("word boundary"),
+                found: Found::from(input.peek()),
+                span: input.to_span_at(0),
+                control_flow: ControlFlow::Recovable,
+            }
+            .into());
+        }
+
+        Ok(Self(input.split_to(0)))
+    }
+
+    #[inline]
+    fn to_span(&self) -> Span {
+        self.0.to_span()
+    }
+}
+
+impl<I> ToSource for WordBoundary<I> {
+    #[inline]
+    fn to_source(&self, _out: &mut String) {}
+}
+
+/// A zero-width syntax node that matches only at the start of a line, consuming nothing:
+/// at the start of the source, or right after a `\n`.
+///
+/// The input must know its previous item, e.g. a [`LookBehindInput`](crate::LookBehindInput).
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct LineStart<I>(pub I);
+
+impl<I> Syntax<I> for LineStart<I>
+where
+    I: LookBehind,
+{
+    #[inline]
+    fn parse(input: &mut I) -> Result<Self, I::Error> {
+        let prev = input.prev_item();
+
+        if prev.is_some() && prev.and_then(|item| item.to_char()) != Some('\n') {
+            return Err(Kind::Expected {
+                what: Expected::Label("line start"),
+                found: Found::from(input.peek()),
+                span: input.to_span_at(0),
+                control_flow: ControlFlow::Recovable,
+            }
+            .into());
+        }
+
+        Ok(Self(input.split_to(0)))
+    }
+
+    #[inline]
+    fn to_span(&self) -> Span {
+        self.0.to_span()
+    }
+}
+
+impl<I> ToSource for LineStart<I> {
+    #[inline]
+    fn to_source(&self, _out: &mut String) {}
+}
+
 impl<T, I> Syntax<I> for Option<T>
 where
     T: Syntax<I>,
@@ -83,13 +293,159 @@ where
     }
 }
 
+impl<T> ToSource for Option<T>
+where
+    T: ToSource,
+{
+    #[inline]
+    fn to_source(&self, out: &mut String) {
+        if let Some(value) = self {
+            value.to_source(out);
+        }
+    }
+}
+
+/// A syntax node like `Option<T>` that falls back to `T::default()` instead of `None`,
+/// consuming nothing.
+///
+/// A defaulted value was not in the source: its span is [`Span::None`] and it writes no source.
+/// Equality, ordering and serde only look at the value, not at whether it was defaulted.
+#[derive(Debug, Clone)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(transparent)
+)]
+pub struct Defaulted<T>(
+    pub T,
+    /// True if `T` was missing.
+    #[cfg_attr(feature = "serde", serde(skip))]
+    bool,
+);
+
+impl<T> Defaulted<T> {
+    /// Creates a value that was present in the source.
+    #[inline]
+    pub fn present(value: T) -> Self {
+        Self(value, false)
+    }
+
+    /// Creates a defaulted value, `T::default()` that was missing from the source.
+    #[inline]
+    #[allow(clippy::self_named_constructors)]
+    pub fn defaulted() -> Self
+    where
+        T: Default,
+    {
+        Self(T::default(), true)
+    }
+
+    /// Returns true if `T` was missing and the value is `T::default()`.
+    #[inline]
+    pub fn is_defaulted(&self) -> bool {
+        self.1
+    }
+
+    /// Returns the parsed or defaulted value.
+    #[inline]
+    pub fn into_inner(self) -> T {
+        self.0
+    }
+}
+
+impl<T> Default for Defaulted<T>
+where
+    T: Default,
+{
+    #[inline]
+    fn default() -> Self {
+        Self::defaulted()
+    }
+}
+
+impl<T> PartialEq for Defaulted<T>
+where
+    T: PartialEq,
+{
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.0 == other.0
+    }
+}
+
+impl<T> Eq for Defaulted<T> where T: Eq {}
+
+impl<T> PartialOrd for Defaulted<T>
+where
+    T: PartialOrd,
+{
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
+        self.0.partial_cmp(&other.0)
+    }
+}
+
+impl<T> Ord for Defaulted<T>
+where
+    T: Ord,
+{
+    #[inline]
+    fn cmp(&self, other: &Self) -> core::cmp::Ordering {
+        self.0.cmp(&other.0)
+    }
+}
+
+impl<T> Deref for Defaulted<T> {
+    type Target = T;
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<T, I> Syntax<I> for Defaulted<T>
+where
+    T: Syntax<I> + Default,
+    I: Input + Clone,
+{
+    #[inline]
+    fn parse(input: &mut I) -> Result<Self, I::Error> {
+        match Option::<T>::parse(input)? {
+            Some(value) => Ok(Self::present(value)),
+            None => Ok(Self::defaulted()),
+        }
+    }
+
+    #[inline]
+    fn to_span(&self) -> Span {
+        if self.is_defaulted() {
+            Span::None
+        } else {
+            self.0.to_span()
+        }
+    }
+}
+
+impl<T> ToSource for Defaulted<T>
+where
+    T: ToSource,
+{
+    #[inline]
+    fn to_source(&self, out: &mut String) {
+        if !self.is_defaulted() {
+            self.0.to_source(out);
+        }
+    }
+}
+
 impl<T, I> Syntax<I> for Box<T>
 where
     T: Syntax<I>,
     I: Input + Clone,
 {
     fn parse(input: &mut I) -> Result<Self, I::Error> {
-        T::into_parser().boxed().parse(input)
+        T::into_parser().map_boxed().parse(input)
     }
 
     #[inline]
@@ -98,6 +454,16 @@ where
     }
 }
 
+impl<T> ToSource for Box<T>
+where
+    T: ToSource,
+{
+    #[inline]
+    fn to_source(&self, out: &mut String) {
+        self.as_ref().to_source(out);
+    }
+}
+
 impl<T, I> Syntax<I> for Vec<T>
 where
     T: Syntax<I>,
@@ -106,7 +472,7 @@ where
     fn parse(input: &mut I) -> Result<Self, I::Error> {
         let mut elms = vec![];
         loop {
-            let elm = T::into_parser().ok().parse(input)?;
+            let elm = T::into_parser().ensure_progress().ok().parse(input)?;
 
             let Some(elm) = elm else {
                 break;
@@ -127,6 +493,18 @@ where
     }
 }
 
+impl<T> ToSource for Vec<T>
+where
+    T: ToSource,
+{
+    #[inline]
+    fn to_source(&self, out: &mut String) {
+        for value in self {
+            value.to_source(out);
+        }
+    }
+}
+
 /// A sytanx node to match a char.
 #[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Clone)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -149,6 +527,16 @@ where
     }
 }
 
+impl<I, const C: char> ToSource for Char<I, C>
+where
+    I: Input + ToSource,
+{
+    #[inline]
+    fn to_source(&self, out: &mut String) {
+        self.0.to_source(out);
+    }
+}
+
 /// A sytanx node to match a byte.
 #[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Clone)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -171,6 +559,16 @@ where
     }
 }
 
+impl<I, const C: u8> ToSource for Byte<I, C>
+where
+    I: Input + ToSource,
+{
+    #[inline]
+    fn to_source(&self, out: &mut String) {
+        self.0.to_source(out);
+    }
+}
+
 /// A short syntax for grouping token that surrounds a syntax body.
 #[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Clone)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -209,55 +607,259 @@ where
     }
 }
 
-/// Limits the child `syntax` length.
+impl<Start, End, Body> ToSource for Delimiter<Start, End, Body>
+where
+    Start: ToSource,
+    End: ToSource,
+    Body: ToSource,
+{
+    #[inline]
+    fn to_source(&self, out: &mut String) {
+        self.start.to_source(out);
+        self.body.to_source(out);
+        self.end.to_source(out);
+    }
+}
+
+/// A syntax node `Start T` that exposes only `T`, see [`Surrounded`].
 #[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Clone)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
-pub struct LimitsTo<T, const N: usize>(pub T);
+pub struct Preceded<Start, T> {
+    start: Start,
+    value: T,
+}
 
-impl<I, T, const N: usize> Syntax<I> for LimitsTo<T, N>
+impl<Start, T> Preceded<Start, T> {
+    /// Returns the wrapped value, discarding the leading token.
+    #[inline]
+    pub fn into_inner(self) -> T {
+        self.value
+    }
+}
+
+impl<Start, T> Deref for Preceded<Start, T> {
+    type Target = T;
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        &self.value
+    }
+}
+
+impl<I, Start, T> Syntax<I> for Preceded<Start, T>
 where
     I: Input,
+    Start: Syntax<I>,
     T: Syntax<I>,
 {
-    fn parse(input: &mut I) -> Result<Self, <I as Input>::Error> {
-        let start = input.to_span();
-
-        let t = T::parse(input)?;
-
-        let span = t.to_span();
-
-        let len = match span {
-            sourcespan::Span::None => 0,
-            sourcespan::Span::Range(range) => range.len(),
-            sourcespan::Span::RangeTo(range_to) => range_to.end,
-            _ => {
-                return Err(Kind::LimitsTo(ControlFlow::Recovable, start).into());
-            }
-        };
-
-        if len > N {
-            return Err(Kind::LimitsTo(ControlFlow::Recovable, start).into());
-        }
+    #[inline]
+    fn parse(input: &mut I) -> Result<Self, I::Error> {
+        let start = Start::parse(input)?;
+        let value = T::parse(input)?;
 
-        Ok(Self(t))
+        Ok(Self { start, value })
     }
 
+    #[inline]
     fn to_span(&self) -> Span {
-        self.0.to_span()
+        self.start.to_span().union(&self.value.to_span())
     }
 }
 
-/// Limits the child `syntax` length between `lower` and `higher`.
+impl<Start, T> ToSource for Preceded<Start, T>
+where
+    Start: ToSource,
+    T: ToSource,
+{
+    #[inline]
+    fn to_source(&self, out: &mut String) {
+        self.start.to_source(out);
+        self.value.to_source(out);
+    }
+}
+
+/// A syntax node `T End` that exposes only `T`, see [`Surrounded`].
 #[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Clone)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
-pub struct Limits<T, const LOWER: usize, const HIGHER: usize>(pub T);
+pub struct Terminated<T, End> {
+    value: T,
+    end: End,
+}
 
-impl<I, T, const LOWER: usize, const HIGHER: usize> Syntax<I> for Limits<T, LOWER, HIGHER>
+impl<T, End> Terminated<T, End> {
+    /// Returns the wrapped value, discarding the trailing token.
+    #[inline]
+    pub fn into_inner(self) -> T {
+        self.value
+    }
+}
+
+impl<T, End> Deref for Terminated<T, End> {
+    type Target = T;
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        &self.value
+    }
+}
+
+impl<I, T, End> Syntax<I> for Terminated<T, End>
 where
     I: Input,
     T: Syntax<I>,
+    End: Syntax<I>,
 {
-    fn parse(input: &mut I) -> Result<Self, <I as Input>::Error> {
+    #[inline]
+    fn parse(input: &mut I) -> Result<Self, I::Error> {
+        let value = T::parse(input)?;
+        let end = End::parse(input)?;
+
+        Ok(Self { value, end })
+    }
+
+    #[inline]
+    fn to_span(&self) -> Span {
+        self.value.to_span().union(&self.end.to_span())
+    }
+}
+
+impl<T, End> ToSource for Terminated<T, End>
+where
+    T: ToSource,
+    End: ToSource,
+{
+    #[inline]
+    fn to_source(&self, out: &mut String) {
+        self.value.to_source(out);
+        self.end.to_source(out);
+    }
+}
+
+/// A syntax node `Start T End` that exposes only `T`, e.g. a parenthesized expression.
+///
+/// Like [`Delimiter`], a missing `End` after `Start` and `T` is a [`fatal`](ControlFlow::Fatal) error,
+/// but the fence tokens are kept private: they only take part in `to_span` and `to_source`,
+/// while the value is reached by `Deref` or [`into_inner`](Surrounded::into_inner).
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Surrounded<Start, T, End> {
+    start: Start,
+    value: T,
+    end: End,
+}
+
+impl<Start, T, End> Surrounded<Start, T, End> {
+    /// Returns the wrapped value, discarding the fence tokens.
+    #[inline]
+    pub fn into_inner(self) -> T {
+        self.value
+    }
+}
+
+impl<Start, T, End> Deref for Surrounded<Start, T, End> {
+    type Target = T;
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        &self.value
+    }
+}
+
+impl<I, Start, T, End> Syntax<I> for Surrounded<Start, T, End>
+where
+    I: Input + Clone,
+    Start: Syntax<I>,
+    T: Syntax<I>,
+    End: Syntax<I>,
+{
+    #[inline]
+    fn parse(input: &mut I) -> Result<Self, I::Error> {
+        let start = Start::parse(input)?;
+        let value = T::parse(input)?;
+        let end = End::into_parser().fatal().parse(input)?;
+
+        Ok(Self { start, value, end })
+    }
+
+    #[inline]
+    fn to_span(&self) -> Span {
+        self.start.to_span().union(&self.end.to_span())
+    }
+}
+
+impl<Start, T, End> ToSource for Surrounded<Start, T, End>
+where
+    Start: ToSource,
+    T: ToSource,
+    End: ToSource,
+{
+    #[inline]
+    fn to_source(&self, out: &mut String) {
+        self.start.to_source(out);
+        self.value.to_source(out);
+        self.end.to_source(out);
+    }
+}
+
+/// Limits the child `syntax` length.
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct LimitsTo<T, const N: usize>(pub T);
+
+impl<I, T, const N: usize> Syntax<I> for LimitsTo<T, N>
+where
+    I: Input,
+    T: Syntax<I>,
+{
+    fn parse(input: &mut I) -> Result<Self, <I as Input>::Error> {
+        let start = input.to_span();
+
+        let t = T::parse(input)?;
+
+        let span = t.to_span();
+
+        let len = match span {
+            sourcespan::Span::None => 0,
+            sourcespan::Span::Range(range) => range.len(),
+            sourcespan::Span::RangeTo(range_to) => range_to.end,
+            _ => {
+                return Err(Kind::LimitsTo(ControlFlow::Recovable, start).into());
+            }
+        };
+
+        if len > N {
+            return Err(Kind::LimitsTo(ControlFlow::Recovable, start).into());
+        }
+
+        Ok(Self(t))
+    }
+
+    fn to_span(&self) -> Span {
+        self.0.to_span()
+    }
+}
+
+impl<T, const N: usize> ToSource for LimitsTo<T, N>
+where
+    T: ToSource,
+{
+    #[inline]
+    fn to_source(&self, out: &mut String) {
+        self.0.to_source(out);
+    }
+}
+
+/// Limits the child `syntax` length between `lower` and `higher`.
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Limits<T, const LOWER: usize, const HIGHER: usize>(pub T);
+
+impl<I, T, const LOWER: usize, const HIGHER: usize> Syntax<I> for Limits<T, LOWER, HIGHER>
+where
+    I: Input,
+    T: Syntax<I>,
+{
+    fn parse(input: &mut I) -> Result<Self, <I as Input>::Error> {
         let start = input.to_span();
 
         let t = T::parse(input)?;
@@ -273,7 +875,7 @@ where
             }
         };
 
-        if len < LOWER || !(len < HIGHER) {
+        if len < LOWER || len >= HIGHER {
             return Err(Kind::Limits(ControlFlow::Recovable, start).into());
         }
 
@@ -285,6 +887,16 @@ where
     }
 }
 
+impl<T, const LOWER: usize, const HIGHER: usize> ToSource for Limits<T, LOWER, HIGHER>
+where
+    T: ToSource,
+{
+    #[inline]
+    fn to_source(&self, out: &mut String) {
+        self.0.to_source(out);
+    }
+}
+
 /// Limits the child `syntax` length must equal or greater than `LOWER`
 #[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Clone)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -323,17 +935,75 @@ where
     }
 }
 
+impl<T, const LOWER: usize> ToSource for LimitsFrom<T, LOWER>
+where
+    T: ToSource,
+{
+    #[inline]
+    fn to_source(&self, out: &mut String) {
+        self.0.to_source(out);
+    }
+}
+
 /// A punctuated sequence of syntax tree nodes of type T separated by punctuation of type P.
+///
+/// Once a separator has matched, the next element is required unless `TRAILING` is true,
+/// in which case the sequence may end with a separator. A separator directly followed by
+/// another separator (e.g. `a,,b`) is always a [`fatal`](ControlFlow::Fatal) error.
 #[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Clone)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
-pub struct Punctuated<T, P> {
+pub struct Punctuated<T, P, const TRAILING: bool = true> {
     /// (T,P) pairs
     pub pairs: Vec<(T, P)>,
     /// individual tail `T`
     pub tail: Option<Box<T>>,
 }
 
-impl<T, P> Punctuated<T, P> {
+impl<T, P, const TRAILING: bool> Default for Punctuated<T, P, TRAILING> {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T, P, const TRAILING: bool> Punctuated<T, P, TRAILING> {
+    /// Creates an empty punctuated sequence.
+    #[inline]
+    pub fn new() -> Self {
+        Self {
+            pairs: vec![],
+            tail: None,
+        }
+    }
+
+    /// Appends a value to the end of this sequence.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the sequence does not end with a separator and is not empty.
+    pub fn push_value(&mut self, value: T) {
+        assert!(
+            self.tail.is_none(),
+            "Punctuated::push_value: cannot push a value without a separator"
+        );
+
+        self.tail = Some(Box::new(value));
+    }
+
+    /// Appends a separator to the end of this sequence.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the sequence is empty or already ends with a separator.
+    pub fn push_punct(&mut self, punct: P) {
+        let value = self
+            .tail
+            .take()
+            .expect("Punctuated::push_punct: cannot push a separator without a value");
+
+        self.pairs.push((*value, punct));
+    }
+
     /// returns the sequence length.
     #[inline]
     pub fn len(&self) -> usize {
@@ -345,21 +1015,163 @@ impl<T, P> Punctuated<T, P> {
     pub fn is_empty(&self) -> bool {
         self.len() == 0
     }
+
+    /// Returns true if the sequence ends with a separator, e.g. `a,b,`.
+    ///
+    /// An empty sequence has no trailing separator.
+    #[inline]
+    pub fn has_trailing(&self) -> bool {
+        self.tail.is_none() && !self.pairs.is_empty()
+    }
+
+    /// Returns an iterator over the elements, skipping separators.
+    #[inline]
+    pub fn iter(&self) -> Iter<'_, T, P> {
+        Iter {
+            pairs: self.pairs.iter(),
+            tail: self.tail.as_deref(),
+        }
+    }
+
+    /// Returns an iterator that allows modifying each element, skipping separators.
+    #[inline]
+    pub fn iter_mut(&mut self) -> IterMut<'_, T, P> {
+        IterMut {
+            pairs: self.pairs.iter_mut(),
+            tail: self.tail.as_deref_mut(),
+        }
+    }
+
+    /// Returns an iterator over the elements together with their following separators.
+    ///
+    /// The separator is `None` only for the tail element.
+    #[inline]
+    pub fn pairs(&self) -> impl Iterator<Item = (&T, Option<&P>)> {
+        self.pairs
+            .iter()
+            .map(|(t, p)| (t, Some(p)))
+            .chain(self.tail.as_deref().map(|t| (t, None)))
+    }
+}
+
+impl<T, P, const TRAILING: bool> FromIterator<(T, P)> for Punctuated<T, P, TRAILING> {
+    #[inline]
+    fn from_iter<It: IntoIterator<Item = (T, P)>>(iter: It) -> Self {
+        Self {
+            pairs: iter.into_iter().collect(),
+            tail: None,
+        }
+    }
+}
+
+/// An iterator over the elements of a [`Punctuated`], created by [`Punctuated::iter`].
+pub struct Iter<'a, T, P> {
+    pairs: core::slice::Iter<'a, (T, P)>,
+    tail: Option<&'a T>,
+}
+
+impl<'a, T, P> Iterator for Iter<'a, T, P> {
+    type Item = &'a T;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.pairs
+            .next()
+            .map(|(t, _)| t)
+            .or_else(|| self.tail.take())
+    }
+}
+
+/// A mutable iterator over the elements of a [`Punctuated`], created by [`Punctuated::iter_mut`].
+pub struct IterMut<'a, T, P> {
+    pairs: core::slice::IterMut<'a, (T, P)>,
+    tail: Option<&'a mut T>,
+}
+
+impl<'a, T, P> Iterator for IterMut<'a, T, P> {
+    type Item = &'a mut T;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.pairs
+            .next()
+            .map(|(t, _)| t)
+            .or_else(|| self.tail.take())
+    }
+}
+
+/// An owning iterator over the elements of a [`Punctuated`].
+pub struct IntoIter<T, P> {
+    pairs: alloc::vec::IntoIter<(T, P)>,
+    tail: Option<Box<T>>,
+}
+
+impl<T, P> Iterator for IntoIter<T, P> {
+    type Item = T;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.pairs
+            .next()
+            .map(|(t, _)| t)
+            .or_else(|| self.tail.take().map(|t| *t))
+    }
+}
+
+impl<T, P, const TRAILING: bool> IntoIterator for Punctuated<T, P, TRAILING> {
+    type Item = T;
+    type IntoIter = IntoIter<T, P>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        IntoIter {
+            pairs: self.pairs.into_iter(),
+            tail: self.tail,
+        }
+    }
+}
+
+impl<'a, T, P, const TRAILING: bool> IntoIterator for &'a Punctuated<T, P, TRAILING> {
+    type Item = &'a T;
+    type IntoIter = Iter<'a, T, P>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<'a, T, P, const TRAILING: bool> IntoIterator for &'a mut Punctuated<T, P, TRAILING> {
+    type Item = &'a mut T;
+    type IntoIter = IterMut<'a, T, P>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter_mut()
+    }
 }
 
-impl<T, P, I> Syntax<I> for Punctuated<T, P>
+impl<T, P, I, const TRAILING: bool> Syntax<I> for Punctuated<T, P, TRAILING>
 where
     T: Syntax<I>,
     P: Syntax<I>,
     I: Input + Clone,
 {
     fn parse(input: &mut I) -> Result<Self, I::Error> {
-        let mut pairs = vec![];
+        let mut pairs: Vec<(T, P)> = vec![];
 
         loop {
+            let start = input.start();
+
             let t = T::into_parser().ok().parse(input)?;
 
             let Some(t) = t else {
+                if !pairs.is_empty()
+                    && (!TRAILING || P::into_parser().ok().parse(&mut input.clone())?.is_some())
+                {
+                    return Err(Kind::Punctuated(ControlFlow::Fatal, input.to_span_at(1)).into());
+                }
+
                 return Ok(Self { pairs, tail: None });
             };
 
@@ -372,6 +1184,10 @@ where
                 });
             };
 
+            if input.start() == start {
+                return Err(Kind::NoProgress(ControlFlow::Fatal, input.to_span_at(0)).into());
+            }
+
             pairs.push((t, p));
         }
     }
@@ -382,6 +1198,18 @@ where
     }
 }
 
+impl<T, P, const TRAILING: bool> ToSource for Punctuated<T, P, TRAILING>
+where
+    T: ToSource,
+    P: ToSource,
+{
+    #[inline]
+    fn to_source(&self, out: &mut String) {
+        self.pairs.to_source(out);
+        self.tail.to_source(out);
+    }
+}
+
 /// When merging two abstract syntax trees,
 /// it first attempts to match the left subtree;
 /// if unsuccessful, it proceeds to match the right subtree.
@@ -392,6 +1220,31 @@ pub enum Or<F, S> {
     Second(S),
 }
 
+impl<F, S> Or<F, S> {
+    /// Collapses both branches into a common type `T`.
+    #[inline]
+    pub fn map_either<A, B, T>(self, fa: A, fb: B) -> T
+    where
+        A: FnOnce(F) -> T,
+        B: FnOnce(S) -> T,
+    {
+        match self {
+            Or::First(v) => fa(v),
+            Or::Second(v) => fb(v),
+        }
+    }
+}
+
+impl<T> Or<T, T> {
+    /// Returns the inner value, whichever branch matched.
+    #[inline]
+    pub fn into_inner(self) -> T {
+        match self {
+            Or::First(v) | Or::Second(v) => v,
+        }
+    }
+}
+
 impl<I, F, S> Syntax<I> for Or<F, S>
 where
     I: Input + Clone,
@@ -417,7 +1270,417 @@ where
     }
 }
 
-// implement Syntax for tuple (T1,T2,...) where T1: Syntax, T2: Syntax, ...
+impl<F, S> ToSource for Or<F, S>
+where
+    F: ToSource,
+    S: ToSource,
+{
+    #[inline]
+    fn to_source(&self, out: &mut String) {
+        match self {
+            Or::First(v) => v.to_source(out),
+            Or::Second(v) => v.to_source(out),
+        }
+    }
+}
+
+type RecursiveCell<I, O> =
+    Rc<RefCell<Option<Box<dyn Fn(&mut I) -> Result<O, <I as Input>::Error>>>>>;
+
+/// A parser that can be referenced before it is defined, for grammars built at runtime.
+///
+/// Create a handle with [`declare`](Self::declare), clone it into the parsers that refer to it,
+/// then provide the actual parser with [`define`](Self::define).
+///
+/// Since [`Parser::parse`] consumes `self`, the definition is stored as a factory that creates
+/// a fresh parser for each call. The factory is boxed as a `'static` trait object, so it can not
+/// borrow from the surrounding scope. A handle captured by its own definition forms a reference
+/// cycle, so the grammar lives until the end of the program.
+pub struct Recursive<I, O>
+where
+    I: Input,
+{
+    parser: RecursiveCell<I, O>,
+}
+
+impl<I, O> Clone for Recursive<I, O>
+where
+    I: Input,
+{
+    #[inline]
+    fn clone(&self) -> Self {
+        Self {
+            parser: self.parser.clone(),
+        }
+    }
+}
+
+impl<I, O> Recursive<I, O>
+where
+    I: Input,
+{
+    /// Declares a parser that will be defined later.
+    #[inline]
+    pub fn declare() -> Self {
+        Self {
+            parser: Rc::new(RefCell::new(None)),
+        }
+    }
+
+    /// Defines the parser with a factory that creates a new parser for each call.
+    ///
+    /// # Panics
+    ///
+    /// Panics if this parser is already defined.
+    pub fn define<F, P>(&self, f: F)
+    where
+        F: Fn() -> P + 'static,
+        P: Parser<I, Output = O>,
+    {
+        let mut parser = self.parser.borrow_mut();
+
+        assert!(parser.is_none(), "Recursive parser is already defined");
+
+        *parser = Some(Box::new(move |input: &mut I| f().parse(input)));
+    }
+}
+
+impl<I, O> Parser<I> for Recursive<I, O>
+where
+    I: Input,
+{
+    type Output = O;
+
+    /// # Panics
+    ///
+    /// Panics if this parser is not yet defined.
+    fn parse(self, input: &mut I) -> Result<Self::Output, I::Error> {
+        let parser = self.parser.borrow();
+
+        let parser = parser
+            .as_ref()
+            .expect("Recursive parser is used before it is defined");
+
+        parser(input)
+    }
+}
+
+// implement Syntax and ToSource for tuple (T1,T2,...) where T1: Syntax, T2: Syntax, ...
 parserc_derive::derive_tuple_syntax!(16);
 
+/// A non-empty sequence of syntax tree nodes of type `T` separated by punctuation of type `P`.
+///
+/// Unlike [`Punctuated`], separators only appear between elements: a separator that is not
+/// followed by an element is left unconsumed.
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SeparatedNonEmpty<T, P> {
+    /// first element.
+    pub first: T,
+    /// rest (P,T) pairs.
+    pub rest: Vec<(P, T)>,
+}
+
+impl<T, P> SeparatedNonEmpty<T, P> {
+    /// Returns an iterator over the elements in order, skipping separators.
+    #[inline]
+    pub fn iter(&self) -> impl Iterator<Item = &T> {
+        core::iter::once(&self.first).chain(self.rest.iter().map(|(_, t)| t))
+    }
+
+    /// Returns an iterator that allows modifying each element, skipping separators.
+    #[inline]
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = &mut T> {
+        core::iter::once(&mut self.first).chain(self.rest.iter_mut().map(|(_, t)| t))
+    }
+
+    /// Returns the last element.
+    #[inline]
+    pub fn last(&self) -> &T {
+        self.rest.last().map_or(&self.first, |(_, t)| t)
+    }
+}
+
+impl<T, P, I> Syntax<I> for SeparatedNonEmpty<T, P>
+where
+    T: Syntax<I>,
+    P: Syntax<I>,
+    I: Input + Clone,
+{
+    fn parse(input: &mut I) -> Result<Self, I::Error> {
+        let first = T::parse(input)?;
+
+        let mut rest = vec![];
+
+        while let Some(pair) = <(P, T)>::into_parser()
+            .ensure_progress()
+            .ok()
+            .parse(input)?
+        {
+            rest.push(pair);
+        }
+
+        Ok(Self { first, rest })
+    }
+
+    #[inline]
+    fn to_span(&self) -> Span {
+        self.first.to_span().union(&self.rest.to_span())
+    }
+}
+
+impl<T, P> ToSource for SeparatedNonEmpty<T, P>
+where
+    T: ToSource,
+    P: ToSource,
+{
+    #[inline]
+    fn to_source(&self, out: &mut String) {
+        self.first.to_source(out);
+        self.rest.to_source(out);
+    }
+}
+
+/// A `key sep value` entry of a [`Map`], e.g. `a = 1`.
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct KeyValue<K, Sep, V> {
+    /// Entry key.
+    pub key: K,
+    /// Separator between the key and the value.
+    pub sep: Sep,
+    /// Entry value.
+    pub value: V,
+}
+
+impl<I, K, Sep, V> Syntax<I> for KeyValue<K, Sep, V>
+where
+    I: Input,
+    K: Syntax<I>,
+    Sep: Syntax<I>,
+    V: Syntax<I>,
+{
+    #[inline]
+    fn parse(input: &mut I) -> Result<Self, I::Error> {
+        let key = K::parse(input)?;
+        let sep = Sep::parse(input)?;
+        let value = V::parse(input)?;
+
+        Ok(Self { key, sep, value })
+    }
+
+    #[inline]
+    fn to_span(&self) -> Span {
+        self.key.to_span().union(&self.value.to_span())
+    }
+}
+
+impl<K, Sep, V> ToSource for KeyValue<K, Sep, V>
+where
+    K: ToSource,
+    Sep: ToSource,
+    V: ToSource,
+{
+    #[inline]
+    fn to_source(&self, out: &mut String) {
+        self.key.to_source(out);
+        self.sep.to_source(out);
+        self.value.to_source(out);
+    }
+}
+
+/// A `start sep end` range, e.g. `1..5` or `a-z`, parsed into a [`Range`].
+///
+/// The separator is injected by its syntax type `Sep`, e.g. a keyword node matching `..`,
+/// and is kept in `sep` for source reconstruction. Convert it into a bare [`Range`] with `into`.
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct RangeOf<T, Sep> {
+    /// The `start..end` bounds.
+    pub range: Range<T>,
+    /// Separator between the bounds.
+    pub sep: Sep,
+}
+
+impl<I, T, Sep> Syntax<I> for RangeOf<T, Sep>
+where
+    I: Input,
+    T: Syntax<I>,
+    Sep: Syntax<I>,
+{
+    #[inline]
+    fn parse(input: &mut I) -> Result<Self, I::Error> {
+        let start = T::parse(input)?;
+        let sep = Sep::parse(input)?;
+        let end = T::parse(input)?;
+
+        Ok(Self {
+            range: start..end,
+            sep,
+        })
+    }
+
+    #[inline]
+    fn to_span(&self) -> Span {
+        self.range.start.to_span().union(&self.range.end.to_span())
+    }
+}
+
+impl<T, Sep> ToSource for RangeOf<T, Sep>
+where
+    T: ToSource,
+    Sep: ToSource,
+{
+    #[inline]
+    fn to_source(&self, out: &mut String) {
+        self.range.start.to_source(out);
+        self.sep.to_source(out);
+        self.range.end.to_source(out);
+    }
+}
+
+impl<T, Sep> From<RangeOf<T, Sep>> for Range<T> {
+    #[inline]
+    fn from(value: RangeOf<T, Sep>) -> Self {
+        value.range
+    }
+}
+
+/// Like [`RangeOf`], but parsed into a [`RangeInclusive`], e.g. `1..=5`.
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct RangeInclusiveOf<T, Sep> {
+    /// The `start..=end` bounds.
+    pub range: RangeInclusive<T>,
+    /// Separator between the bounds.
+    pub sep: Sep,
+}
+
+impl<I, T, Sep> Syntax<I> for RangeInclusiveOf<T, Sep>
+where
+    I: Input,
+    T: Syntax<I>,
+    Sep: Syntax<I>,
+{
+    #[inline]
+    fn parse(input: &mut I) -> Result<Self, I::Error> {
+        let start = T::parse(input)?;
+        let sep = Sep::parse(input)?;
+        let end = T::parse(input)?;
+
+        Ok(Self {
+            range: start..=end,
+            sep,
+        })
+    }
+
+    #[inline]
+    fn to_span(&self) -> Span {
+        self.range
+            .start()
+            .to_span()
+            .union(&self.range.end().to_span())
+    }
+}
+
+impl<T, Sep> ToSource for RangeInclusiveOf<T, Sep>
+where
+    T: ToSource,
+    Sep: ToSource,
+{
+    #[inline]
+    fn to_source(&self, out: &mut String) {
+        self.range.start().to_source(out);
+        self.sep.to_source(out);
+        self.range.end().to_source(out);
+    }
+}
+
+impl<T, Sep> From<RangeInclusiveOf<T, Sep>> for RangeInclusive<T> {
+    #[inline]
+    fn from(value: RangeInclusiveOf<T, Sep>) -> Self {
+        value.range
+    }
+}
+
+/// A syntax node that collects repeated [`KeyValue`] entries into the map `M`,
+/// either a `BTreeMap<String, KeyValue<..>>` or a `HashMap<String, KeyValue<..>>`.
+///
+/// Entries are keyed by the source text of their keys, see [`ToSource`], so equal keys
+/// at different offsets collide while the key spans stay out of the comparison.
+/// Trivia around a key belongs to the separator or the previous value, not to the key.
+/// A duplicated key is a [`fatal`](ControlFlow::Fatal) [`Kind::DuplicateKey`] error.
+///
+/// The source order of the entries is not kept: a `BTreeMap` iterates in key order
+/// and a `HashMap` in arbitrary order, use `Vec<KeyValue<..>>` if the order matters.
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Map<M>(pub M);
+
+macro_rules! impl_map_syntax {
+    ($map: ident) => {
+        impl<I, K, Sep, V> Syntax<I> for Map<$map<String, KeyValue<K, Sep, V>>>
+        where
+            I: Input + Clone,
+            K: Syntax<I> + ToSource,
+            Sep: Syntax<I>,
+            V: Syntax<I>,
+        {
+            fn parse(input: &mut I) -> Result<Self, I::Error> {
+                let mut map = $map::new();
+
+                while let Some(entry) = KeyValue::<K, Sep, V>::into_parser()
+                    .ensure_progress()
+                    .ok()
+                    .parse(input)?
+                {
+                    let mut key = String::new();
+                    entry.key.to_source(&mut key);
+
+                    if map.contains_key(&key) {
+                        return Err(
+                            Kind::DuplicateKey(ControlFlow::Fatal, entry.key.to_span()).into()
+                        );
+                    }
+
+                    map.insert(key, entry);
+                }
+
+                Ok(Self(map))
+            }
+
+            #[inline]
+            fn to_span(&self) -> Span {
+                self.0
+                    .values()
+                    .fold(Span::None, |span, entry| span.union(&entry.to_span()))
+            }
+        }
+    };
+}
+
+impl_map_syntax!(BTreeMap);
+#[cfg(feature = "std")]
+impl_map_syntax!(HashMap);
+
+/// A `transparent` struct parses as its single field, so options that would
+/// be dropped are rejected, e.g. `skip_trivia` on the struct:
+///
+/// ```compile_fail
+/// use parserc::{chars::CharsInput, syntax::{Char, Syntax}};
+///
+/// #[derive(Syntax)]
+/// #[parserc(transparent, skip_trivia = parserc::take_while(|c: char| c == ' '))]
+/// struct Comma<I: CharsInput>(Char<I, ','>);
+/// ```
+///
+/// or a `parserc` option on its field:
+///
+/// ```compile_fail
+/// use parserc::{chars::CharsInput, syntax::{Char, Syntax}};
+///
+/// #[derive(Syntax)]
+/// #[parserc(transparent)]
+/// struct Comma<I: CharsInput>(#[parserc(crucial)] Char<I, ','>);
+/// ```
 pub use parserc_derive::Syntax;
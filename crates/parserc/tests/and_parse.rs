@@ -0,0 +1,64 @@
+mod common;
+
+use common::TokenStream;
+use parserc::{
+    ControlFlow, Expected, Found, Kind, Parser, Span, keyword, next, sep_end_by0, take_until,
+    take_while,
+};
+
+/// Parses a `"..."` region, then the comma terminated words inside of it.
+fn quoted_words<'a>() -> impl Parser<TokenStream<'a>, Output = Vec<TokenStream<'a>>> {
+    |input: &mut TokenStream<'a>| {
+        next('"').parse(input)?;
+
+        let words = take_until("\"")
+            .and_parse(sep_end_by0(
+                || take_while(|c: char| c.is_ascii_alphabetic()),
+                || next(','),
+            ))
+            .parse(input)?;
+
+        next('"').parse(input)?;
+
+        Ok(words)
+    }
+}
+
+#[test]
+fn test_and_parse() {
+    let mut input = TokenStream::from(r#""a,bc,";"#);
+
+    assert_eq!(
+        quoted_words().parse(&mut input),
+        Ok(vec![
+            TokenStream::from((1, "a")),
+            TokenStream::from((3, "bc"))
+        ])
+    );
+    assert_eq!(input, TokenStream::from((7, ";")));
+}
+
+#[test]
+fn test_leftover_in_slice() {
+    let mut input = TokenStream::from(r#""a,b";"#);
+
+    assert_eq!(
+        quoted_words().parse(&mut input),
+        Err(Kind::Eof(ControlFlow::Recovable, Span::Range(3..4)))
+    );
+}
+
+#[test]
+fn test_inner_sees_slice_only() {
+    assert_eq!(
+        take_until(";")
+            .and_parse(keyword("ab;"))
+            .parse(&mut TokenStream::from("ab;")),
+        Err(Kind::Expected {
+            what: Expected::Keyword("ab;".into()),
+            found: Found::Char('a'),
+            span: Span::Range(0..2),
+            control_flow: ControlFlow::Recovable,
+        })
+    );
+}
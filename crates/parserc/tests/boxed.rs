@@ -0,0 +1,77 @@
+mod common;
+
+use common::TokenStream;
+use parserc::{BoxedParser, Kind, Parser, keyword, next};
+
+#[test]
+fn test_boxed_parsers() {
+    let mut parsers: Vec<BoxedParser<'static, TokenStream<'static>, TokenStream<'static>>> = vec![
+        (|input: &mut TokenStream<'static>| keyword("let").parse(input)).erase(),
+        (|input: &mut TokenStream<'static>| next('=').parse(input)).erase(),
+        BoxedParser::new(|input: &mut TokenStream<'static>| keyword("1").parse(input)),
+    ];
+
+    let mut input = TokenStream::from("let=1");
+
+    let outputs = parsers
+        .iter_mut()
+        .map(|parser| parser.parse(&mut input))
+        .collect::<Result<Vec<_>, Kind>>();
+
+    assert_eq!(
+        outputs,
+        Ok(vec![
+            TokenStream::from((0, "let")),
+            TokenStream::from((3, "=")),
+            TokenStream::from((4, "1"))
+        ])
+    );
+
+    let mut input = TokenStream::from("=");
+
+    assert!((&mut parsers[0]).parse(&mut input).is_err());
+    assert_eq!(
+        parsers.remove(1).parse(&mut input),
+        Ok(TokenStream::from((0, "=")))
+    );
+}
+
+struct Grammar {
+    keyword: BoxedParser<'static, TokenStream<'static>, TokenStream<'static>>,
+}
+
+#[test]
+fn test_erase_field() {
+    let mut grammar = Grammar {
+        keyword: (|input: &mut TokenStream<'static>| keyword("let").parse(input)).erase(),
+    };
+
+    let mut input = TokenStream::from("letlet");
+    assert_eq!(
+        (&mut grammar.keyword).parse(&mut input),
+        Ok(TokenStream::from((0, "let")))
+    );
+    assert_eq!(
+        grammar.keyword.parse(&mut input),
+        Ok(TokenStream::from((3, "let")))
+    );
+}
+
+#[test]
+fn test_map_boxed() {
+    let mut input = TokenStream::from("let");
+    assert_eq!(
+        keyword("let").map_boxed().parse(&mut input),
+        Ok(Box::new(TokenStream::from("let")))
+    );
+}
+
+#[test]
+#[allow(deprecated)]
+fn test_boxed_deprecated() {
+    let mut input = TokenStream::from("let");
+    assert_eq!(
+        keyword("let").boxed().parse(&mut input),
+        Ok(Box::new(TokenStream::from("let")))
+    );
+}
@@ -0,0 +1,422 @@
+mod common;
+
+use common::TokenStream;
+use parserc::{
+    AsStr, ControlFlow, Expected, FnMutParser, Found, Input, Kind, Parser, Span, between,
+    collect_separated_spans, cond, count, fold_left, keyword, next, next_if, opt_preceded,
+    separated_pair, take_while, take_while_range_from, take_while_streaming, with_leading_ws,
+};
+
+/// Parses an identifier, optionally prefixed by `r#` when the dialect supports raw identifiers.
+fn ident<'a>(raw: bool) -> impl Parser<TokenStream<'a>, Output = TokenStream<'a>> {
+    move |input: &mut TokenStream<'a>| {
+        cond(raw, keyword("r#").ok()).parse(input)?;
+        take_while(|c: char| c.is_ascii_alphabetic() || c == '#').parse(input)
+    }
+}
+
+#[test]
+fn test_cond_enabled() {
+    let mut input = TokenStream::from("r#type");
+
+    assert_eq!(
+        ident(true).parse(&mut input),
+        Ok(TokenStream::from((2, "type")))
+    );
+
+    assert_eq!(
+        cond(true, keyword("r#")).parse(&mut TokenStream::from("type")),
+        Err(Kind::Expected {
+            what: Expected::Keyword("r#".into()),
+            found: Found::Char('t'),
+            span: Span::Range(0..2),
+            control_flow: ControlFlow::Recovable,
+        })
+    );
+}
+
+#[test]
+fn test_cond_disabled() {
+    let mut input = TokenStream::from("r#type");
+
+    assert_eq!(cond(false, keyword("r#")).parse(&mut input), Ok(None));
+    assert_eq!(input, TokenStream::from("r#type"));
+
+    assert_eq!(
+        ident(false).parse(&mut input),
+        Ok(TokenStream::from("r#type"))
+    );
+}
+
+/// Parses `{a, b}`.
+fn group<'a>(input: &mut TokenStream<'a>) -> Result<(), Kind> {
+    next('{').parse(input)?;
+    take_while(|c: char| c.is_ascii_alphabetic() || c == ',' || c == ' ').parse(input)?;
+    next('}').parse(input)?;
+    Ok(())
+}
+
+#[test]
+fn test_context_span() {
+    let mut input = TokenStream::from("use {a, b;");
+    keyword("use ").parse(&mut input).unwrap();
+
+    assert_eq!(
+        group.context_span("use-tree").parse(&mut input.clone()),
+        Err(Kind::Syntax(
+            "use-tree",
+            ControlFlow::Recovable,
+            Span::Range(4..10)
+        ))
+    );
+
+    assert_eq!(
+        group.label("use-tree").parse(&mut input.clone()),
+        Err(Kind::Syntax(
+            "use-tree",
+            ControlFlow::Recovable,
+            Span::Range(9..10)
+        ))
+    );
+
+    assert_eq!(
+        group
+            .fatal()
+            .context_span("use-tree")
+            .parse(&mut input.clone()),
+        Err(Kind::Syntax(
+            "use-tree",
+            ControlFlow::Fatal,
+            Span::Range(4..10)
+        ))
+    );
+
+    assert_eq!(
+        group
+            .context_span("use-tree")
+            .parse(&mut TokenStream::from("{a}")),
+        Ok(())
+    );
+}
+
+#[test]
+fn test_fn_mut_parser() {
+    let mut seen = vec![];
+
+    let parser = FnMutParser::new(|input: &mut TokenStream<'static>| {
+        seen.push(input.start());
+        next('a').parse(input)
+    });
+
+    let mut input = TokenStream::from("aaab");
+    assert_eq!(count(|| &parser).parse(&mut input), Ok(3));
+    assert_eq!(input, TokenStream::from((3, "b")));
+
+    let mut input = TokenStream::from("aa");
+    assert_eq!(
+        between(1, 2, || &parser).parse(&mut input),
+        Ok(vec![
+            TokenStream::from((0, "a")),
+            TokenStream::from((1, "a"))
+        ])
+    );
+
+    assert_eq!(seen, [0, 1, 2, 3, 0, 1]);
+}
+
+#[test]
+fn test_fn_mut_parser_by_value() {
+    let mut calls = 0;
+
+    let parser = FnMutParser::new(|input: &mut TokenStream<'static>| {
+        calls += 1;
+        next('a').parse(input)
+    });
+
+    assert_eq!(
+        parser.parse(&mut TokenStream::from("ab")),
+        Ok(TokenStream::from("a"))
+    );
+    assert_eq!(calls, 1);
+}
+
+#[derive(Debug, PartialEq)]
+enum Expr {
+    Num(u32),
+    Sub(Box<Expr>, Box<Expr>),
+}
+
+fn num() -> impl Parser<TokenStream<'static>, Output = Expr> {
+    take_while_range_from(1, |c: char| c.is_ascii_digit())
+        .map(|digits: TokenStream<'static>| Expr::Num(digits.as_str().parse().unwrap()))
+}
+
+#[test]
+fn test_fold_left() {
+    let mut input = TokenStream::from("1-2-3-");
+
+    assert_eq!(
+        fold_left(
+            num,
+            || next('-'),
+            |lhs, _, rhs| { Expr::Sub(Box::new(lhs), Box::new(rhs)) }
+        )
+        .parse(&mut input),
+        Ok(Expr::Sub(
+            Box::new(Expr::Sub(Box::new(Expr::Num(1)), Box::new(Expr::Num(2)))),
+            Box::new(Expr::Num(3))
+        ))
+    );
+
+    assert_eq!(input, TokenStream::from((5, "-")));
+
+    let mut input = TokenStream::from("1");
+
+    assert_eq!(
+        fold_left(
+            num,
+            || next('-'),
+            |lhs, _, rhs| { Expr::Sub(Box::new(lhs), Box::new(rhs)) }
+        )
+        .parse(&mut input),
+        Ok(Expr::Num(1))
+    );
+}
+
+#[test]
+fn test_not_followed_by() {
+    let mut input = TokenStream::from("int x");
+    assert_eq!(
+        keyword("int")
+            .not_followed_by(next_if(|c: char| c.is_ascii_alphanumeric()))
+            .parse(&mut input),
+        Ok(TokenStream::from((0, "int")))
+    );
+    assert_eq!(input, TokenStream::from((3, " x")));
+
+    let mut input = TokenStream::from("int");
+    assert_eq!(
+        keyword("int")
+            .not_followed_by(next_if(|c: char| c.is_ascii_alphanumeric()))
+            .parse(&mut input),
+        Ok(TokenStream::from((0, "int")))
+    );
+
+    let mut input = TokenStream::from("integer");
+    assert_eq!(
+        keyword("int")
+            .not_followed_by(next_if(|c: char| c.is_ascii_alphanumeric()))
+            .parse(&mut input),
+        Err(Kind::NotFollowedBy(
+            ControlFlow::Recovable,
+            Span::Range(3..4)
+        ))
+    );
+    assert_eq!(input, TokenStream::from("integer"));
+}
+
+fn path<'a>() -> impl Parser<TokenStream<'a>, Output = (Option<TokenStream<'a>>, TokenStream<'a>)> {
+    opt_preceded(
+        keyword("::"),
+        take_while_range_from(1, |c: char| c.is_ascii_alphabetic()),
+    )
+}
+
+#[test]
+fn test_opt_preceded_prefix_present() {
+    let mut input = TokenStream::from("::path");
+
+    assert_eq!(
+        path().parse(&mut input),
+        Ok((
+            Some(TokenStream::from((0, "::"))),
+            TokenStream::from((2, "path"))
+        ))
+    );
+    assert_eq!(input, TokenStream::from((6, "")));
+}
+
+#[test]
+fn test_opt_preceded_prefix_absent() {
+    assert_eq!(
+        path().parse(&mut TokenStream::from("path")),
+        Ok((None, TokenStream::from((0, "path"))))
+    );
+}
+
+#[test]
+fn test_opt_preceded_body_error() {
+    assert_eq!(
+        path().parse(&mut TokenStream::from("::1")),
+        Err(Kind::TakeWhileFrom(
+            ControlFlow::Recovable,
+            Span::Range(2..2)
+        ))
+    );
+}
+
+fn key_value<'a>() -> impl Parser<TokenStream<'a>, Output = (TokenStream<'a>, TokenStream<'a>)> {
+    separated_pair(
+        take_while_range_from(1, |c: char| c.is_ascii_alphabetic()),
+        next('='),
+        take_while_range_from(1, |c: char| c.is_ascii_alphabetic()),
+    )
+}
+
+#[test]
+fn test_separated_pair() {
+    let mut input = TokenStream::from("k=v;");
+
+    assert_eq!(
+        key_value().parse(&mut input),
+        Ok((TokenStream::from("k"), TokenStream::from((2, "v"))))
+    );
+    assert_eq!(input, TokenStream::from((3, ";")));
+}
+
+#[test]
+fn test_separated_pair_missing_separator() {
+    assert_eq!(
+        key_value().parse(&mut TokenStream::from("k:v")),
+        Err(Kind::Expected {
+            what: Expected::Char('='),
+            found: Found::Char(':'),
+            span: Span::Range(1..2),
+            control_flow: ControlFlow::Recovable,
+        })
+    );
+}
+
+fn path_spans(input: &mut TokenStream<'_>) -> Result<Vec<Span>, Kind> {
+    collect_separated_spans(
+        || take_while(|c: char| c.is_ascii_alphabetic()),
+        || keyword("::"),
+    )
+    .parse(input)
+}
+
+#[test]
+fn test_collect_separated_spans() {
+    let mut input = TokenStream::from("a::bc::d");
+    assert_eq!(
+        path_spans(&mut input),
+        Ok(vec![
+            Span::Range(0..1),
+            Span::Range(3..5),
+            Span::Range(7..8)
+        ])
+    );
+    assert_eq!(input, TokenStream::from((8, "")));
+
+    let mut input = TokenStream::from("a::b::");
+    assert_eq!(
+        collect_separated_spans(|| keyword("a").or(keyword("b")), || keyword("::"))
+            .parse(&mut input),
+        Ok(vec![Span::Range(0..1), Span::Range(3..4)])
+    );
+    assert_eq!(input, TokenStream::from((4, "::")));
+
+    assert_eq!(
+        collect_separated_spans(|| keyword("a"), || keyword("::"))
+            .parse(&mut TokenStream::from("b")),
+        Err(Kind::Expected {
+            what: Expected::Keyword("a".into()),
+            found: Found::Char('b'),
+            span: Span::Range(0..1),
+            control_flow: ControlFlow::Recovable,
+        })
+    );
+}
+
+#[test]
+fn test_take_while_streaming() {
+    let mut input = TokenStream::from("abc1");
+    assert_eq!(
+        take_while_streaming(|c: char| c.is_ascii_alphabetic()).parse(&mut input),
+        Ok(TokenStream::from((0, "abc")))
+    );
+    assert_eq!(input, TokenStream::from((3, "1")));
+
+    let mut input = TokenStream::from("1");
+    assert_eq!(
+        take_while_streaming(|c: char| c.is_ascii_alphabetic()).parse(&mut input),
+        Ok(TokenStream::from((0, "")))
+    );
+
+    let mut input = TokenStream::from("abc");
+    assert_eq!(
+        take_while_streaming(|c: char| c.is_ascii_alphabetic()).parse(&mut input),
+        Err(Kind::TakeWhile(ControlFlow::Incomplete, Span::Range(0..3)))
+    );
+    assert_eq!(input, TokenStream::from("abc"));
+
+    let mut input = TokenStream::from("abc");
+    assert_eq!(
+        take_while(|c: char| c.is_ascii_alphabetic()).parse(&mut input),
+        Ok(TokenStream::from((0, "abc")))
+    );
+}
+
+#[test]
+fn test_terminated_by_eof() {
+    let mut input = TokenStream::from("abc");
+
+    assert_eq!(
+        take_while(|c: char| c.is_ascii_alphabetic())
+            .terminated_by_eof()
+            .parse(&mut input),
+        Ok(TokenStream::from("abc"))
+    );
+    assert_eq!(input, TokenStream::from((3, "")));
+}
+
+#[test]
+fn test_terminated_by_eof_leftover_span() {
+    let mut input = TokenStream::from("let x = 中文;");
+
+    assert_eq!(
+        keyword("let").terminated_by_eof().parse(&mut input),
+        Err(Kind::Eof(ControlFlow::Recovable, Span::Range(3..15)))
+    );
+
+    let mut input = TokenStream::from((10, "ab  "));
+
+    assert_eq!(
+        take_while(|c: char| c.is_ascii_alphabetic())
+            .terminated_by_eof()
+            .parse(&mut input),
+        Err(Kind::Eof(ControlFlow::Recovable, Span::Range(12..14)))
+    );
+}
+
+#[test]
+fn test_with_leading_ws() {
+    let mut input = TokenStream::from("   token;");
+
+    assert_eq!(
+        with_leading_ws(keyword("token")).parse(&mut input),
+        Ok((Span::Range(0..3), TokenStream::from((3, "token"))))
+    );
+    assert_eq!(input, TokenStream::from((8, ";")));
+
+    assert_eq!(
+        with_leading_ws(keyword("token")).parse(&mut TokenStream::from((5, "\n\u{3000}\ttoken"))),
+        Ok((Span::Range(5..10), TokenStream::from((10, "token"))))
+    );
+}
+
+#[test]
+fn test_with_leading_ws_no_ws() {
+    assert_eq!(
+        with_leading_ws(keyword("token")).parse(&mut TokenStream::from("token")),
+        Ok((Span::None, TokenStream::from("token")))
+    );
+}
+
+#[test]
+fn test_with_leading_ws_restore_on_error() {
+    let mut input = TokenStream::from("  other");
+
+    assert!(with_leading_ws(keyword("token")).parse(&mut input).is_err());
+    assert_eq!(input, TokenStream::from("  other"));
+}
@@ -0,0 +1,25 @@
+//! Fixtures shared by the integration tests.
+#![allow(dead_code)]
+
+use parserc::{
+    Kind,
+    chars::{self, CharsInput},
+    syntax::Syntax,
+};
+
+/// The input type of the tests.
+pub type TokenStream<'a> = chars::TokenStream<'a, Kind>;
+
+/// An ascii alphabetic identifier.
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Clone, Syntax)]
+#[parserc(display, take_while = |c: char| c.is_ascii_alphabetic())]
+pub struct Ident<I>(pub I)
+where
+    I: CharsInput;
+
+/// A run of ascii digits.
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Clone, Syntax)]
+#[parserc(take_while = |c: char| c.is_ascii_digit())]
+pub struct Digits<I>(pub I)
+where
+    I: CharsInput;
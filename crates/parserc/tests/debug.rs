@@ -0,0 +1,81 @@
+#![cfg(feature = "debug")]
+
+mod common;
+
+use common::TokenStream;
+use parserc::{
+    Parser,
+    debug::{ParseTrace, TraceNode},
+    next,
+};
+
+/// `parens -> '(' parens ')' | 'x'`
+fn parens(trace: &ParseTrace) -> impl Parser<TokenStream<'static>, Output = ()> {
+    let trace = trace.clone();
+
+    move |input: &mut TokenStream<'static>| {
+        let nested = {
+            let trace = trace.clone();
+            move |input: &mut TokenStream<'static>| {
+                next('(').parse(input)?;
+                parens(&trace).parse(input)?;
+                next(')').parse(input)?;
+                Ok(())
+            }
+        };
+
+        nested
+            .debug("nested", &trace)
+            .or(next('x').map(|_| ()).debug("x", &trace))
+            .debug("parens", &trace)
+            .parse(input)
+    }
+}
+
+fn node(label: &'static str, offset: usize, ok: bool, children: Vec<TraceNode>) -> TraceNode {
+    TraceNode {
+        label,
+        offset,
+        ok,
+        children,
+    }
+}
+
+#[test]
+fn test_trace_tree() {
+    let trace = ParseTrace::new();
+
+    assert_eq!(parens(&trace).parse(&mut TokenStream::from("(x)")), Ok(()));
+
+    assert_eq!(
+        trace.roots(),
+        [node(
+            "parens",
+            0,
+            true,
+            vec![node(
+                "nested",
+                0,
+                true,
+                vec![node(
+                    "parens",
+                    1,
+                    true,
+                    vec![node("nested", 1, false, vec![]), node("x", 1, true, vec![])]
+                )]
+            )]
+        )]
+    );
+
+    assert_eq!(
+        trace.pretty_print(),
+        "parens @0 ok\n  nested @0 ok\n    parens @1 ok\n      nested @1 failed\n      x @1 ok\n"
+    );
+
+    trace.clear();
+    assert!(parens(&trace).parse(&mut TokenStream::from("y")).is_err());
+    assert_eq!(
+        trace.pretty_print(),
+        "parens @0 failed\n  nested @0 failed\n  x @0 failed\n"
+    );
+}
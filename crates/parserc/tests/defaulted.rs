@@ -0,0 +1,81 @@
+mod common;
+
+use common::TokenStream;
+use parserc::{
+    Input, Kind, Parser, Span, dec_uint,
+    syntax::{Defaulted, Syntax, SyntaxInput, ToSource},
+};
+
+/// A repeat count like `3`, `1` if omitted.
+#[derive(Debug, PartialEq)]
+struct Count(usize, Span);
+
+impl Default for Count {
+    fn default() -> Self {
+        Self(1, Span::Range(0..0))
+    }
+}
+
+impl<'a> Syntax<TokenStream<'a>> for Count {
+    fn parse(input: &mut TokenStream<'a>) -> Result<Self, Kind> {
+        let start = input.start();
+        let value = dec_uint().parse(input)?;
+        Ok(Self(value, Span::Range(start..input.start())))
+    }
+
+    fn to_span(&self) -> Span {
+        self.1.clone()
+    }
+}
+
+impl ToSource for Count {
+    fn to_source(&self, out: &mut String) {
+        out.push_str(&self.0.to_string());
+    }
+}
+
+#[test]
+fn test_present() {
+    let mut input = TokenStream::from("12x");
+    let count = input.parse::<Defaulted<Count>>().unwrap();
+
+    assert!(!count.is_defaulted());
+    assert_eq!(count.0, Count(12, Span::Range(0..2)));
+    assert_eq!(count.to_span(), Span::Range(0..2));
+
+    let mut source = String::new();
+    count.to_source(&mut source);
+    assert_eq!(source, "12");
+
+    assert_eq!(input, TokenStream::from((2, "x")));
+}
+
+#[test]
+fn test_absent() {
+    let mut input = TokenStream::from("x");
+    let count = input.parse::<Defaulted<Count>>().unwrap();
+
+    assert!(count.is_defaulted());
+    assert_eq!(count.0.0, 1);
+    assert_eq!(count.to_span(), Span::None);
+
+    let mut source = String::new();
+    count.to_source(&mut source);
+    assert_eq!(source, "");
+
+    assert_eq!(input, TokenStream::from("x"));
+}
+
+#[test]
+fn test_constructors() {
+    let present = Defaulted::present(Count(1, Span::Range(0..0)));
+    assert!(!present.is_defaulted());
+    assert_eq!(present.to_span(), Span::Range(0..0));
+
+    let defaulted = Defaulted::<Count>::defaulted();
+    assert!(defaulted.is_defaulted());
+    assert_eq!(defaulted.to_span(), Span::None);
+
+    assert_eq!(present, defaulted);
+    assert!(Defaulted::<Count>::default().is_defaulted());
+}
@@ -0,0 +1,60 @@
+mod common;
+
+use common::TokenStream;
+use parserc::{ControlFlow, ParseError, Parser, Span, delimited_list, next, take_while_range_from};
+
+fn list<'a>()
+-> impl Parser<TokenStream<'a>, Output = (TokenStream<'a>, Vec<TokenStream<'a>>, TokenStream<'a>)> {
+    delimited_list(
+        next('['),
+        || take_while_range_from(1, |c: char| c.is_ascii_alphabetic()),
+        || next(','),
+        next(']'),
+    )
+}
+
+#[test]
+fn test_delimited_list() {
+    let mut input = TokenStream::from("[a,b,c]");
+
+    assert_eq!(
+        list().parse(&mut input),
+        Ok((
+            TokenStream::from((0, "[")),
+            vec![
+                TokenStream::from((1, "a")),
+                TokenStream::from((3, "b")),
+                TokenStream::from((5, "c")),
+            ],
+            TokenStream::from((6, "]")),
+        ))
+    );
+    assert_eq!(input, TokenStream::from((7, "")));
+
+    let (_, elements, close) = list().parse(&mut TokenStream::from("[a,]")).unwrap();
+    assert_eq!(elements, vec![TokenStream::from((1, "a"))]);
+    assert_eq!(close, TokenStream::from((3, "]")));
+
+    let (_, elements, _) = list().parse(&mut TokenStream::from("[]")).unwrap();
+    assert!(elements.is_empty());
+}
+
+#[test]
+fn test_unclosed() {
+    let err = list().parse(&mut TokenStream::from("[a,b")).unwrap_err();
+
+    assert_eq!(err.control_flow(), ControlFlow::Fatal);
+    assert_eq!(err.to_span(), Span::Range(4..4));
+
+    let err = list().parse(&mut TokenStream::from("[a b]")).unwrap_err();
+
+    assert_eq!(err.control_flow(), ControlFlow::Fatal);
+    assert_eq!(err.to_span(), Span::Range(2..3));
+}
+
+#[test]
+fn test_no_open() {
+    let err = list().parse(&mut TokenStream::from("a,b]")).unwrap_err();
+
+    assert_eq!(err.control_flow(), ControlFlow::Recovable);
+}
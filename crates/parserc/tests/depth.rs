@@ -0,0 +1,47 @@
+mod common;
+
+use common::TokenStream;
+use parserc::{ControlFlow, DepthInput, Kind, Parser, Span, depth_limit, next};
+
+type Input<'a> = DepthInput<TokenStream<'a>>;
+
+/// Returns the nesting depth of balanced parens.
+fn parens(input: &mut Input<'_>) -> Result<usize, Kind> {
+    depth_limit(
+        |input: &mut Input<'_>| {
+            if next('(').ok().parse(input)?.is_none() {
+                return Ok(0);
+            }
+
+            let depth = parens(input)?;
+            next(')').parse(input)?;
+
+            Ok(depth + 1)
+        },
+        100,
+    )
+    .parse(input)
+}
+
+#[test]
+fn test_depth_limit() {
+    let mut input = DepthInput::new(TokenStream::from("((()))"));
+    assert_eq!(parens(&mut input), Ok(3));
+    assert_eq!(input.depth(), 0);
+
+    let source = format!("{}{}", "(".repeat(99), ")".repeat(99));
+    let mut input = DepthInput::new(TokenStream::from(source.as_str()));
+    assert_eq!(parens(&mut input), Ok(99));
+}
+
+#[test]
+fn test_depth_limit_exceeded() {
+    let source = format!("{}{}", "(".repeat(1_000_000), ")".repeat(1_000_000));
+    let mut input = DepthInput::new(TokenStream::from(source.as_str()));
+
+    assert_eq!(
+        parens(&mut input),
+        Err(Kind::DepthLimit(ControlFlow::Fatal, Span::Range(100..101)))
+    );
+    assert_eq!(input.depth(), 0);
+}
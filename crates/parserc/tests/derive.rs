@@ -1,13 +1,14 @@
+mod common;
+
 use std::fmt::Debug;
 
+use common::TokenStream;
 use parserc::{
-    ControlFlow, Kind, Span,
-    chars::{self, CharsInput},
-    syntax::{Syntax, SyntaxInput},
+    ControlFlow, Expected, Found, Input, Kind, Parser, Span,
+    chars::CharsInput,
+    syntax::{Char, Punctuated, Syntax, SyntaxInput},
 };
 
-type TokenStream<'a> = chars::TokenStream<'a, Kind>;
-
 #[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Syntax)]
 #[parserc(take_while = |c: char| c.is_ascii_alphabetic())]
 struct Ident<I>(pub I)
@@ -35,6 +36,145 @@ where
     pub ident: Ident<I>,
 }
 
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Syntax)]
+#[parserc(skip_trivia = parserc::take_while(|c: char| c.is_whitespace()))]
+struct Triple<I>
+where
+    I: CharsInput,
+{
+    pub a: Ident<I>,
+    pub b: Ident<I>,
+    pub c: Ident<I>,
+}
+
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Syntax)]
+#[parserc(skip_trivia = parserc::take_while(|c: char| c == ' '))]
+enum Pair<I>
+where
+    I: CharsInput,
+{
+    Assign(Ident<I>, Char<I, '='>, Ident<I>),
+}
+
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Syntax)]
+struct Node<S, T>
+where
+    S: CharsInput,
+    T: Syntax<S>,
+{
+    pub name: Ident<S>,
+    pub eq: Char<S, '='>,
+    pub value: T,
+}
+
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Syntax)]
+enum Either<T, Input: CharsInput>
+where
+    T: Syntax<Input>,
+{
+    Value(Char<Input, '!'>, T),
+    Ident(Ident<Input>),
+}
+
+type Args<'a> = Punctuated<Ident<TokenStream<'a>>, Char<TokenStream<'a>, ','>>;
+
+/// Parses `[a,b,...]`.
+fn bracketed_args<'a>(input: &mut TokenStream<'a>) -> Result<Args<'a>, Kind> {
+    parserc::next('[').parse(input)?;
+    let args = input.parse()?;
+    parserc::next(']').parse(input)?;
+    Ok(args)
+}
+
+#[derive(Debug, PartialEq, Syntax)]
+#[parserc(input = TokenStream<'a>)]
+struct Call<'a> {
+    pub name: Ident<TokenStream<'a>>,
+    #[parserc(try_parser = bracketed_args)]
+    pub args: Args<'a>,
+}
+
+#[derive(Debug, PartialEq, Syntax)]
+#[parserc(display, keyword = "let")]
+struct Let<I>(pub I)
+where
+    I: CharsInput;
+
+#[derive(Debug, PartialEq, Syntax)]
+#[parserc(display, take_while = |c: char| c == ' ')]
+struct Spaces<I>(pub I)
+where
+    I: CharsInput;
+
+#[derive(Debug, PartialEq, Syntax)]
+#[parserc(display, take_while = |c: char| c.is_ascii_alphabetic())]
+struct Word<I>(pub I)
+where
+    I: CharsInput;
+
+#[derive(Debug, PartialEq, Syntax)]
+#[parserc(display)]
+struct Binding<I>
+where
+    I: CharsInput,
+{
+    pub keyword: Let<I>,
+    pub spaces: Spaces<I>,
+    pub name: Word<I>,
+}
+
+#[derive(Debug, PartialEq, Syntax)]
+#[parserc(display)]
+enum Statement<I>
+where
+    I: CharsInput,
+{
+    Binding(Binding<I>),
+    Word { word: Word<I> },
+}
+
+#[derive(Debug, PartialEq, Syntax)]
+#[parserc(display, transparent)]
+struct Declaration<I>(pub Binding<I>)
+where
+    I: CharsInput;
+
+#[derive(Debug, PartialEq, Syntax)]
+#[parserc(transparent, map_err = ParseError::into_fatal)]
+struct Name<I>
+where
+    I: CharsInput,
+{
+    pub word: Word<I>,
+}
+
+#[derive(Debug, PartialEq, Syntax)]
+struct Hashes<I>
+where
+    I: CharsInput,
+{
+    #[parserc(repeat(min = 2, max = 4))]
+    pub hashes: Vec<Char<I, '#'>>,
+}
+
+#[derive(Debug, PartialEq, Syntax)]
+struct List<I>
+where
+    I: CharsInput,
+{
+    #[parserc(separated_by = Char<I, ','>)]
+    pub items: Vec<Ident<I>>,
+}
+
+#[derive(Debug, PartialEq, Syntax)]
+struct Statements<I>
+where
+    I: CharsInput,
+{
+    #[parserc(separated_by = Char<I, ';'>, trailing)]
+    pub items: Vec<Ident<I>>,
+}
+
 #[test]
 fn test_derive() {
     assert_eq!(
@@ -55,3 +195,163 @@ fn test_left_recursion() {
         Err(Kind::LeftRecursion(ControlFlow::Fatal, Span::Range(0..0)))
     );
 }
+
+#[test]
+fn test_skip_trivia() {
+    let mut input = TokenStream::from("a \n\t bc  d ");
+    assert_eq!(
+        input.parse(),
+        Ok(Triple {
+            a: Ident(TokenStream::from((0, "a"))),
+            b: Ident(TokenStream::from((5, "bc"))),
+            c: Ident(TokenStream::from((9, "d"))),
+        })
+    );
+    assert_eq!(input, TokenStream::from((10, " ")));
+
+    let mut input = TokenStream::from(" a");
+    assert!(input.parse::<Triple<_>>().is_err());
+
+    let mut input = TokenStream::from("x = y");
+    assert_eq!(
+        input.parse(),
+        Ok(Pair::Assign(
+            Ident(TokenStream::from((0, "x"))),
+            Char(TokenStream::from((2, "="))),
+            Ident(TokenStream::from((4, "y"))),
+        ))
+    );
+}
+
+#[test]
+fn test_generic_params() {
+    let mut input = TokenStream::from("a=b");
+    let node: Node<_, Ident<_>> = input.parse().unwrap();
+    assert_eq!(node.value, Ident(TokenStream::from((2, "b"))));
+
+    let mut input = TokenStream::from("a=!b");
+    let node: Node<_, Either<Ident<_>, _>> = input.parse().unwrap();
+    assert_eq!(
+        node.value,
+        Either::Value(
+            Char(TokenStream::from((2, "!"))),
+            Ident(TokenStream::from((3, "b")))
+        )
+    );
+}
+
+#[test]
+fn test_try_parser() {
+    let mut input = TokenStream::from("f[a,b]");
+    let call: Call<'_> = input.parse().unwrap();
+    assert_eq!(call.args.len(), 2);
+    assert!(input.value.is_empty());
+
+    let mut input = TokenStream::from("f[a!");
+    let call: Call<'_> = input.parse().unwrap();
+    assert_eq!(call.name, Ident(TokenStream::from((0, "f"))));
+    assert!(call.args.is_empty());
+    assert_eq!(input, TokenStream::from((1, "[a!")));
+}
+
+#[test]
+fn test_display() {
+    use parserc::syntax::ToSource;
+
+    for source in ["let  x", "abc"] {
+        let statement = TokenStream::from(source).parse::<Statement<_>>().unwrap();
+
+        let mut out = String::new();
+        statement.to_source(&mut out);
+
+        assert_eq!(out, source);
+        assert_eq!(statement.to_string(), source);
+    }
+
+    let binding = TokenStream::from("let x").parse::<Binding<_>>().unwrap();
+
+    assert_eq!(binding.keyword.to_string(), "let");
+    assert_eq!(binding.to_string(), "let x");
+}
+
+#[test]
+fn test_transparent() {
+    let mut input = TokenStream::from("let x;");
+    let declaration = input.parse::<Declaration<_>>().unwrap();
+
+    assert_eq!(
+        declaration,
+        Declaration(TokenStream::from("let x").parse::<Binding<_>>().unwrap())
+    );
+    assert_eq!(declaration.to_span(), Span::Range(0..5));
+    assert_eq!(declaration.to_string(), "let x");
+    assert_eq!(input, TokenStream::from((5, ";")));
+
+    assert_eq!(
+        TokenStream::from("x").parse::<Declaration<_>>(),
+        Err(Kind::Expected {
+            what: Expected::Keyword("let".into()),
+            found: Found::Char('x'),
+            span: Span::Range(0..1),
+            control_flow: ControlFlow::Recovable,
+        })
+    );
+
+    let name = TokenStream::from("abc").parse::<Name<_>>().unwrap();
+    assert_eq!(name.word.to_span(), Span::Range(0..3));
+
+    assert_eq!(
+        TokenStream::from("1").parse::<Name<_>>(),
+        Err(Kind::TakeWhileFrom(ControlFlow::Fatal, Span::Range(0..0)))
+    );
+}
+
+#[test]
+fn test_repeat() {
+    assert_eq!(
+        TokenStream::from("#").parse::<Hashes<_>>(),
+        Err(Kind::Between(ControlFlow::Recovable, Span::Range(0..1)))
+    );
+
+    let mut input = TokenStream::from("### title");
+    let hashes = input.parse::<Hashes<_>>().unwrap();
+    assert_eq!(hashes.hashes.len(), 3);
+    assert_eq!(hashes.to_span(), Span::Range(0..3));
+    assert_eq!(input, TokenStream::from((3, " title")));
+
+    let mut input = TokenStream::from("#####");
+    assert_eq!(
+        input.parse::<Hashes<_>>(),
+        Err(Kind::Between(ControlFlow::Recovable, Span::Range(0..4)))
+    );
+    assert_eq!(input, TokenStream::from("#####"));
+}
+
+#[test]
+fn test_separated_by() {
+    let mut input = TokenStream::from("a,b,c");
+    let list = input.parse::<List<_>>().unwrap();
+    assert_eq!(
+        list.items,
+        [
+            Ident(TokenStream::from((0, "a"))),
+            Ident(TokenStream::from((2, "b"))),
+            Ident(TokenStream::from((4, "c")))
+        ]
+    );
+    assert!(input.is_empty());
+
+    let mut input = TokenStream::from("a,b,");
+    assert!(input.parse::<List<_>>().is_err());
+
+    let mut input = TokenStream::from("a;b;");
+    let statements = input.parse::<Statements<_>>().unwrap();
+    assert_eq!(
+        statements.items,
+        [
+            Ident(TokenStream::from((0, "a"))),
+            Ident(TokenStream::from((2, "b")))
+        ]
+    );
+    assert!(input.is_empty());
+}
@@ -0,0 +1,222 @@
+mod common;
+
+use common::TokenStream;
+use parserc::{
+    ControlFlow, Deescalate, Expected, Found, Kind, ParseError, Parser, Span, bytes, keyword,
+    keywords, next, next_if,
+};
+
+#[test]
+fn test_expected() {
+    let err = next('a').parse(&mut TokenStream::from("b")).unwrap_err();
+    assert_eq!(
+        err,
+        Kind::Expected {
+            what: Expected::Char('a'),
+            found: Found::Char('b'),
+            span: Span::Range(0..1),
+            control_flow: ControlFlow::Recovable
+        }
+    );
+    assert_eq!(err.to_string(), "expected `a`, found `b`");
+
+    let err = next('a').parse(&mut TokenStream::from("")).unwrap_err();
+    assert_eq!(err.to_string(), "expected `a`, found end of input");
+
+    let err = next_if(|c: char| c.is_ascii_digit())
+        .parse(&mut TokenStream::from("\n"))
+        .unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "expected item matching the predicate, found `\\n`"
+    );
+
+    let err = keyword("let")
+        .parse(&mut TokenStream::from("x = 1"))
+        .unwrap_err();
+    assert_eq!(err.to_string(), "expected `let`, found `x`");
+    assert_eq!(
+        keyword("let").parse(&mut TokenStream::from("")),
+        Err(Kind::Expected {
+            what: Expected::Keyword("let".into()),
+            found: Found::Eof,
+            span: Span::Range(0..0),
+            control_flow: ControlFlow::Recovable
+        })
+    );
+}
+
+#[test]
+fn test_found() {
+    let err = next(b'a')
+        .parse(&mut bytes::ByteStream::<Kind>::from(&[0xffu8][..]))
+        .unwrap_err();
+    assert_eq!(
+        err,
+        Kind::Expected {
+            what: Expected::Char('a'),
+            found: Found::Other,
+            span: Span::Range(0..1),
+            control_flow: ControlFlow::Recovable
+        }
+    );
+    assert_eq!(err.to_string(), "expected `a`, found a non-character item");
+
+    let err = keywords(&["..", "..="])
+        .parse(&mut TokenStream::from("="))
+        .unwrap_err();
+    assert_eq!(err.to_string(), "expected one of `..`, `..=`, found `=`");
+}
+
+fn pair(first: char, second: char) -> impl Parser<TokenStream<'static>, Output = ()> {
+    move |input: &mut TokenStream<'static>| {
+        next(first).parse(input)?;
+        next(second).fatal().parse(input)?;
+        Ok(())
+    }
+}
+
+#[test]
+fn test_recoverable() {
+    let mut input = TokenStream::from("ab");
+    assert!(pair('a', 'c').or(pair('a', 'b')).parse(&mut input).is_err());
+
+    let mut input = TokenStream::from("ab");
+    assert_eq!(
+        pair('a', 'c')
+            .recoverable()
+            .or(pair('a', 'b'))
+            .parse(&mut input),
+        Ok(())
+    );
+    assert_eq!(input, TokenStream::from((2, "")));
+
+    assert_eq!(ControlFlow::Fatal.deescalate(), ControlFlow::Recovable);
+    assert_eq!(
+        ControlFlow::Incomplete.deescalate(),
+        ControlFlow::Incomplete
+    );
+    assert_eq!(ControlFlow::Recovable.escalate(), ControlFlow::Fatal);
+}
+
+/// Fails to compile if a `Kind` variant is missing from [`all_kinds`].
+#[allow(deprecated)]
+fn assert_listed(kind: &Kind) {
+    match kind {
+        Kind::Next(..) => {}
+        Kind::NextIf(..) => {}
+        Kind::Keyword(..) => {}
+        Kind::Expected { .. } => {}
+        Kind::Syntax(..) => {}
+        Kind::Token(..) => {}
+        Kind::LimitsTo(..) => {}
+        Kind::Limits(..) => {}
+        Kind::LimitsFrom(..) => {}
+        Kind::TakeUntil(..) => {}
+        Kind::TakeWhileRange(..) => {}
+        Kind::TakeWhileFrom(..) => {}
+        Kind::TakeWhileTo(..) => {}
+        Kind::LeftRecursion(..) => {}
+        Kind::Punctuated(..) => {}
+        Kind::TakeWhile(..) => {}
+        Kind::Permutation(..) => {}
+        Kind::Eof(..) => {}
+        Kind::NotFollowedBy(..) => {}
+        Kind::FuelExhausted(..) => {}
+        Kind::DepthLimit(..) => {}
+        Kind::Between(..) => {}
+        Kind::Overflow(..) => {}
+        Kind::DuplicateKey(..) => {}
+        Kind::Verify(..) => {}
+        Kind::NoProgress(..) => {}
+    }
+}
+
+#[allow(deprecated)]
+fn all_kinds(control_flow: ControlFlow) -> Vec<Kind> {
+    let span = Span::Range(1..2);
+
+    vec![
+        Kind::Next(control_flow, span.clone()),
+        Kind::NextIf(control_flow, span.clone()),
+        Kind::Keyword(control_flow, span.clone()),
+        Kind::Expected {
+            what: Expected::Label("x"),
+            found: Found::Char('y'),
+            span: span.clone(),
+            control_flow,
+        },
+        Kind::Syntax("x", control_flow, span.clone()),
+        Kind::Token("x", control_flow, span.clone()),
+        Kind::LimitsTo(control_flow, span.clone()),
+        Kind::Limits(control_flow, span.clone()),
+        Kind::LimitsFrom(control_flow, span.clone()),
+        Kind::TakeUntil(control_flow, span.clone()),
+        Kind::TakeWhileRange(control_flow, span.clone()),
+        Kind::TakeWhileFrom(control_flow, span.clone()),
+        Kind::TakeWhileTo(control_flow, span.clone()),
+        Kind::LeftRecursion(control_flow, span.clone()),
+        Kind::Punctuated(control_flow, span.clone()),
+        Kind::TakeWhile(control_flow, span.clone()),
+        Kind::Permutation(control_flow, span.clone()),
+        Kind::Eof(control_flow, span.clone()),
+        Kind::NotFollowedBy(control_flow, span.clone()),
+        Kind::FuelExhausted(control_flow, span.clone()),
+        Kind::DepthLimit(control_flow, span.clone()),
+        Kind::Between(control_flow, span.clone()),
+        Kind::Overflow(control_flow, span.clone()),
+        Kind::DuplicateKey(control_flow, span.clone()),
+        Kind::Verify(control_flow, span.clone()),
+        Kind::NoProgress(control_flow, span.clone()),
+    ]
+}
+
+#[test]
+fn test_into_fatal_preserves_variant() {
+    for kind in all_kinds(ControlFlow::Recovable) {
+        assert_listed(&kind);
+
+        let fatal = kind.clone().into_fatal();
+        assert_eq!(
+            std::mem::discriminant(&fatal),
+            std::mem::discriminant(&kind),
+            "{:?}",
+            kind
+        );
+        assert_eq!(fatal.control_flow(), ControlFlow::Fatal, "{:?}", kind);
+        assert_eq!(fatal.to_span(), kind.to_span(), "{:?}", kind);
+        assert_eq!(fatal.deescalate(), kind);
+    }
+
+    assert_eq!(
+        all_kinds(ControlFlow::Recovable)
+            .into_iter()
+            .map(ParseError::into_fatal)
+            .collect::<Vec<_>>(),
+        all_kinds(ControlFlow::Fatal)
+    );
+}
+
+#[test]
+fn test_sort_key() {
+    let mut errors = [
+        Kind::Eof(ControlFlow::Recovable, Span::None),
+        Kind::Syntax("b", ControlFlow::Fatal, Span::Range(4..8)),
+        Kind::Syntax("c", ControlFlow::Recovable, Span::RangeFrom(4..)),
+        Kind::Syntax("a", ControlFlow::Recovable, Span::Range(4..6)),
+        Kind::TakeUntil(ControlFlow::Incomplete, Span::Range(0..1)),
+    ];
+
+    errors.sort_by_key(ParseError::sort_key);
+
+    assert_eq!(
+        errors.iter().map(ParseError::to_span).collect::<Vec<_>>(),
+        [
+            Span::Range(0..1),
+            Span::Range(4..6),
+            Span::Range(4..8),
+            Span::RangeFrom(4..),
+            Span::None
+        ]
+    );
+}
@@ -0,0 +1,82 @@
+mod common;
+
+use common::TokenStream;
+use parserc::{
+    AsStr, ControlFlow, Fuel, FuelInput, Input as _, Kind, Parser, Span, StartWith, count, next,
+};
+
+type Input<'a> = FuelInput<TokenStream<'a>>;
+
+/// `X -> 'a' X 'b' | 'a' X 'c' | 'a'`, exponential on `aaa...a` without memoization.
+fn x(input: &mut Input<'_>) -> Result<usize, Kind> {
+    (|input: &mut Input<'_>| {
+        next('a').parse(input)?;
+        let depth = x(input)?;
+        next('b').parse(input)?;
+        Ok(depth + 1)
+    })
+    .or(|input: &mut Input<'_>| {
+        next('a').parse(input)?;
+        let depth = x(input)?;
+        next('c').parse(input)?;
+        Ok(depth + 1)
+    })
+    .or(next('a').map(|_| 0))
+    .parse(input)
+}
+
+#[test]
+fn test_with_fuel() {
+    let mut input = FuelInput::new(TokenStream::from("aaabc"), usize::MAX);
+
+    assert_eq!(x.with_fuel(100).parse(&mut input), Ok(2));
+    assert_eq!(input.into_inner(), TokenStream::from((5, "")));
+}
+
+#[test]
+fn test_fuel_exhausted() {
+    let source = "a".repeat(64);
+    let mut input = FuelInput::new(TokenStream::from(source.as_str()), usize::MAX);
+
+    assert_eq!(
+        x.with_fuel(10_000).parse(&mut input),
+        Err(Kind::FuelExhausted(ControlFlow::Fatal, Span::Range(0..1)))
+    );
+    assert_eq!(input.remaining(), usize::MAX - 10_001);
+}
+
+#[test]
+fn test_nested_fuel() {
+    // every element burns fuel, the inner budgets must not reset the outer one.
+    let element = || next('b').or(next('a')).with_fuel(100);
+
+    let source = "a".repeat(64);
+    let mut input = FuelInput::new(TokenStream::from(source.as_str()), usize::MAX);
+
+    assert_eq!(
+        count(element).with_fuel(20).parse(&mut input),
+        Err(Kind::FuelExhausted(ControlFlow::Fatal, Span::Range(6..7)))
+    );
+
+    let mut input = FuelInput::new(TokenStream::from(source.as_str()), usize::MAX);
+    assert_eq!(count(element).with_fuel(1_000).parse(&mut input), Ok(64));
+    assert_eq!(input.remaining(), usize::MAX - 195);
+
+    // the budget of the input caps every `with_fuel`.
+    let mut input = FuelInput::new(TokenStream::from(source.as_str()), 20);
+    assert!(count(element).with_fuel(1_000).parse(&mut input).is_err());
+    assert!(input.is_exhausted());
+}
+
+#[test]
+fn test_exhausted_reads_empty() {
+    let input = FuelInput::new(TokenStream::from("abc"), 0);
+    let _ = input.clone();
+
+    assert!(input.is_exhausted());
+    assert!(input.is_empty());
+    assert_eq!(input.peek(), None);
+    assert_eq!(input.as_str(), "");
+    assert_eq!(input.starts_with("a"), None);
+    assert_eq!(input.to_span_at(1), Span::Range(0..1));
+}
@@ -0,0 +1,208 @@
+mod common;
+
+use common::TokenStream;
+use parserc::{
+    AsStr, FindAll, Input, Kind, Parser, take_while, take_while_range, take_while_range_to,
+};
+
+#[test]
+fn test_iter_indices_are_byte_offsets() {
+    let source = "aé中🦀b";
+    let input = TokenStream::from((10, source));
+
+    let mut expected = 0;
+    for (index, c) in input.iter_indices() {
+        assert_eq!(index, expected);
+        assert!(source[index..].starts_with(c));
+        expected += c.len_utf8();
+    }
+
+    assert_eq!(expected, input.len());
+    assert!(input.char_indices().eq(input.iter_indices()));
+
+    let mut input = TokenStream::from("aé中b");
+    let (index, _) = input.iter_indices().nth(3).unwrap();
+    assert_eq!(input.split_to(index), TokenStream::from((0, "aé中")));
+}
+
+#[test]
+fn test_take_while_range_multi_byte() {
+    let mut input = TokenStream::from("é中🦀b");
+    assert_eq!(
+        take_while_range(2..3, |c: char| !c.is_ascii()).parse(&mut input),
+        Ok(TokenStream::from((0, "é中")))
+    );
+    assert_eq!(input, TokenStream::from((5, "🦀b")));
+
+    let mut input = TokenStream::from("é中");
+    assert!(
+        take_while_range(3..4, |c: char| !c.is_ascii())
+            .parse(&mut input)
+            .is_err()
+    );
+
+    let mut input = TokenStream::from("é中");
+    assert_eq!(
+        take_while_range(0..1, |c: char| !c.is_ascii()).parse(&mut input),
+        Ok(TokenStream::from((0, "")))
+    );
+
+    let mut input = TokenStream::from("é中");
+    assert_eq!(
+        take_while_range_to(1, |c: char| !c.is_ascii()).parse(&mut input),
+        Ok(TokenStream::from((0, "")))
+    );
+
+    let mut input = TokenStream::from("é中🦀");
+    assert_eq!(
+        take_while_range_to(3, |c: char| !c.is_ascii()).parse(&mut input),
+        Ok(TokenStream::from((0, "é中")))
+    );
+}
+
+#[test]
+fn test_peek() {
+    let input = TokenStream::from("中a");
+    assert_eq!(input.peek(), Some('中'));
+    assert_eq!(input.peek(), input.iter().next());
+    assert_eq!(input, TokenStream::from("中a"));
+
+    assert_eq!(TokenStream::from("").peek(), None);
+
+    let input = parserc::bytes::TokenStream::<Kind>::from("ab");
+    assert_eq!(input.peek(), Some(b'a'));
+    assert_eq!(input.peek(), input.iter().next());
+}
+
+#[test]
+fn test_peek_n() {
+    let input = TokenStream::from((3, "..=中"));
+    assert_eq!(input.peek_n(2), TokenStream::from((3, "..")));
+    assert_eq!(input.peek_n(4), TokenStream::from((3, "..=中")));
+    assert_eq!(input.peek_n(0), TokenStream::from((3, "")));
+    assert_eq!(input, TokenStream::from((3, "..=中")));
+
+    let input = TokenStream::from("中a");
+    assert_eq!(input.peek_n(1), TokenStream::from("中"));
+    assert_eq!(input.peek_n(4), TokenStream::from("中a"));
+
+    let input = parserc::bytes::TokenStream::<Kind>::from("(?:");
+    assert_eq!(input.peek_n(2).as_str(), "(?");
+    assert_eq!(input.peek_n(4).as_str(), "(?:");
+}
+
+#[test]
+fn test_find_all() {
+    let input = TokenStream::from("ababab");
+    assert_eq!(input.find_all("ab"), vec![0, 2, 4]);
+    assert_eq!(input.find_all("aba"), vec![0]);
+    assert_eq!(input.find_all("c"), Vec::<usize>::new());
+
+    let input = TokenStream::from((10, "中a中"));
+    assert_eq!(input.find_all("中"), vec![0, 4]);
+    assert_eq!(input.find_all(b"a"), vec![3]);
+
+    let input = parserc::bytes::TokenStream::<Kind>::from("a,b,c");
+    assert_eq!(input.find_all(&b","[..]), vec![1, 3]);
+}
+
+#[test]
+fn test_with_offset() {
+    let source = r#"x = "a + bc""#;
+    let body = &source[5..11];
+
+    let mut input = TokenStream::from(body).with_offset(5);
+    assert_eq!(input.to_span(), parserc::Span::Range(5..11));
+
+    let lhs = take_while(|c: char| c.is_ascii_alphabetic())
+        .parse(&mut input)
+        .unwrap();
+    assert_eq!(lhs.to_span(), parserc::Span::Range(5..6));
+    assert_eq!(&source[5..6], "a");
+
+    take_while(|c: char| !c.is_ascii_alphabetic())
+        .parse(&mut input)
+        .unwrap();
+
+    let rhs = take_while(|c: char| c.is_ascii_alphabetic())
+        .parse(&mut input)
+        .unwrap();
+    assert_eq!(rhs.to_span(), parserc::Span::Range(9..11));
+    assert_eq!(&source[9..11], "bc");
+
+    assert_eq!(
+        TokenStream::from((2, "a")).with_offset(3),
+        TokenStream::from((5, "a"))
+    );
+}
+
+#[test]
+fn test_try_as_str() {
+    use parserc::{TryAsStr, bytes::ByteStream, keyword, take_until};
+
+    let source: &[u8] = b"ok:\xFFend";
+    let mut input = ByteStream::<Kind>::from(source);
+
+    assert_eq!(input.try_as_str().unwrap_err().valid_up_to(), 3);
+
+    let head = keyword("ok:").parse(&mut input).unwrap();
+    assert_eq!(head, ByteStream::from((0, &b"ok:"[..])));
+    assert_eq!(head.try_as_str(), Ok("ok:"));
+
+    let invalid = take_until("end").parse(&mut input).unwrap();
+    assert_eq!(invalid.to_span(), parserc::Span::Range(3..4));
+    assert!(invalid.try_as_str().is_err());
+
+    assert_eq!(input.try_as_str(), Ok("end"));
+    assert_eq!(format!("{:?}", invalid), r#"ByteStream::from((3,b"\xff"))"#);
+
+    assert_eq!(TokenStream::from("abc").try_as_str(), Ok("abc"));
+}
+
+#[test]
+fn test_lines() {
+    let input = TokenStream::from("key = 1\r\n\nname = x\n");
+
+    let lines = input.lines().collect::<Vec<_>>();
+    assert_eq!(
+        lines,
+        [
+            TokenStream::from((0, "key = 1")),
+            TokenStream::from((9, "")),
+            TokenStream::from((10, "name = x"))
+        ]
+    );
+    assert_eq!(lines[2].offset, 10);
+    assert_eq!(lines[2].value, "name = x");
+
+    assert_eq!(
+        TokenStream::from("a\nb").lines().collect::<Vec<_>>(),
+        [TokenStream::from((0, "a")), TokenStream::from((2, "b"))]
+    );
+    assert_eq!(TokenStream::from("").lines().count(), 0);
+}
+
+#[test]
+fn test_checked_split_to() {
+    let mut input = TokenStream::from("aé");
+
+    assert_eq!(input.checked_split_to(2), None);
+    assert_eq!(input.checked_split_to(4), None);
+    assert_eq!(input, TokenStream::from("aé"));
+
+    assert_eq!(input.checked_split_to(1), Some(TokenStream::from("a")));
+    assert_eq!(input, TokenStream::from((1, "é")));
+
+    let mut input = parserc::bytes::ByteStream::<Kind>::from(&b"ab"[..]);
+    assert_eq!(input.checked_split_to(3), None);
+    assert_eq!(
+        input.checked_split_to(1),
+        Some(parserc::bytes::ByteStream::from((0, &b"a"[..])))
+    );
+}
+
+#[test]
+#[should_panic(expected = "not a char boundary")]
+fn test_split_to_mid_char() {
+    TokenStream::from("é").split_to(1);
+}
@@ -0,0 +1,85 @@
+mod common;
+
+use common::TokenStream;
+use parserc::{ControlFlow, Expected, Found, Kind, Parser, Span, keyword_find, keywords};
+
+const OPS: &[&str] = &["<", "..", "<=", "..=", "<<="];
+
+#[test]
+fn test_keywords_longest_match() {
+    let mut input = TokenStream::from("..=1");
+    assert_eq!(
+        keywords(OPS).parse(&mut input),
+        Ok((TokenStream::from((0, "..=")), 3))
+    );
+    assert_eq!(input, TokenStream::from((3, "1")));
+
+    let mut input = TokenStream::from("..1");
+    assert_eq!(
+        keywords(OPS).parse(&mut input),
+        Ok((TokenStream::from((0, "..")), 1))
+    );
+
+    let mut input = TokenStream::from("<<=");
+    assert_eq!(
+        keywords(OPS).parse(&mut input),
+        Ok((TokenStream::from((0, "<<=")), 4))
+    );
+
+    let mut input = TokenStream::from("<=");
+    assert_eq!(
+        keywords(OPS).parse(&mut input),
+        Ok((TokenStream::from((0, "<=")), 2))
+    );
+}
+
+#[test]
+fn test_keywords_first_of_duplicates() {
+    let mut input = TokenStream::from("ab");
+    assert_eq!(
+        keywords(&["a", "ab", "ab"]).parse(&mut input),
+        Ok((TokenStream::from((0, "ab")), 1))
+    );
+}
+
+#[test]
+fn test_keywords_no_match() {
+    let mut input = TokenStream::from("=");
+    assert_eq!(
+        keywords(OPS).parse(&mut input),
+        Err(Kind::Expected {
+            what: Expected::OneOf(OPS),
+            found: Found::Char('='),
+            span: Span::Range(0..1),
+            control_flow: ControlFlow::Recovable
+        })
+    );
+    assert_eq!(input, TokenStream::from("="));
+}
+
+#[test]
+fn test_keyword_find() {
+    let mut input = TokenStream::from("let x = let");
+    assert_eq!(
+        keyword_find("let").parse(&mut input),
+        Ok(TokenStream::from("let"))
+    );
+    assert_eq!(input, TokenStream::from((3, " x = let")));
+
+    assert_eq!(
+        keyword_find("let").parse(&mut input),
+        Err(Kind::Expected {
+            what: Expected::Keyword("let".into()),
+            found: Found::Char(' '),
+            span: Span::Range(3..6),
+            control_flow: ControlFlow::Recovable
+        })
+    );
+    assert_eq!(input, TokenStream::from((3, " x = let")));
+
+    assert!(
+        keyword_find("let")
+            .parse(&mut TokenStream::from("le"))
+            .is_err()
+    );
+}
@@ -0,0 +1,80 @@
+mod common;
+
+use std::collections::{BTreeMap, HashMap};
+
+use common::{Digits, Ident, TokenStream};
+use parserc::{
+    ControlFlow, Kind, Span,
+    chars::CharsInput,
+    syntax::{Char, KeyValue, Map, Syntax, SyntaxInput},
+};
+
+#[derive(Debug, PartialEq, Syntax)]
+#[parserc(take_while = |c: char| c == ' ')]
+struct Spaces<I>(pub I)
+where
+    I: CharsInput;
+
+#[derive(Debug, PartialEq, Syntax)]
+struct Value<I>
+where
+    I: CharsInput,
+{
+    digits: Digits<I>,
+    spaces: Option<Spaces<I>>,
+}
+
+type Entry<I> = KeyValue<Ident<I>, (Option<Spaces<I>>, Char<I, '='>), Value<I>>;
+
+#[test]
+fn test_btree_map() {
+    let mut input = TokenStream::from("b=2 a=1");
+    let map: Map<BTreeMap<String, Entry<_>>> = input.parse().unwrap();
+
+    assert_eq!(
+        map.0.keys().map(String::as_str).collect::<Vec<_>>(),
+        vec!["a", "b"]
+    );
+    assert_eq!(map.0["a"].value.digits, Digits(TokenStream::from((6, "1"))));
+    assert_eq!(map.0["b"].key.0, TokenStream::from((0, "b")));
+    assert_eq!(map.to_span(), Span::Range(0..7));
+    assert_eq!(input, TokenStream::from((7, "")));
+}
+
+#[test]
+fn test_hash_map() {
+    let map: Map<HashMap<String, Entry<_>>> = TokenStream::from("a=1 b=2").parse().unwrap();
+
+    assert_eq!(map.0.len(), 2);
+    assert_eq!(map.0["b"].value.digits, Digits(TokenStream::from((6, "2"))));
+
+    let map: Map<HashMap<String, Entry<_>>> = TokenStream::from("").parse().unwrap();
+
+    assert!(map.0.is_empty());
+    assert_eq!(map.to_span(), Span::None);
+}
+
+#[test]
+fn test_duplicate_key() {
+    assert_eq!(
+        TokenStream::from("a=1 a=2").parse::<Map<BTreeMap<String, Entry<_>>>>(),
+        Err(Kind::DuplicateKey(ControlFlow::Fatal, Span::Range(4..5)))
+    );
+
+    assert_eq!(
+        TokenStream::from("a=1 b=2 a =3").parse::<Map<HashMap<String, Entry<_>>>>(),
+        Err(Kind::DuplicateKey(ControlFlow::Fatal, Span::Range(8..9)))
+    );
+}
+
+#[test]
+fn test_no_progress() {
+    type Empty<I> = KeyValue<Option<Ident<I>>, Option<Spaces<I>>, Option<Spaces<I>>>;
+
+    assert_eq!(
+        TokenStream::from("1")
+            .parse::<Map<BTreeMap<String, Empty<_>>>>()
+            .map(|map| map.0.len()),
+        Err(Kind::NoProgress(ControlFlow::Fatal, Span::Range(0..0)))
+    );
+}
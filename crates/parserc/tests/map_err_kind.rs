@@ -0,0 +1,103 @@
+use parserc::{
+    ControlFlow, IntoErrorKind, Kind, ParseError, Parser, Span, chars::TokenStream, next, next_if,
+};
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+enum LitKind {
+    Digit,
+    Hex,
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, thiserror::Error)]
+enum MyError {
+    #[error(transparent)]
+    Kind(#[from] Kind),
+    #[error("expect {0:?}")]
+    Lit(LitKind, ControlFlow, Span),
+    #[error("expect punct")]
+    Punct(ControlFlow, Span),
+}
+
+impl ParseError for MyError {
+    fn to_span(&self) -> Span {
+        match self {
+            MyError::Kind(kind) => kind.to_span(),
+            MyError::Lit(_, _, span) | MyError::Punct(_, span) => span.clone(),
+        }
+    }
+
+    fn control_flow(&self) -> ControlFlow {
+        match self {
+            MyError::Kind(kind) => kind.control_flow(),
+            MyError::Lit(_, control_flow, _) | MyError::Punct(control_flow, _) => *control_flow,
+        }
+    }
+
+    fn into_fatal(self) -> Self {
+        match self {
+            MyError::Kind(kind) => MyError::Kind(kind.into_fatal()),
+            MyError::Lit(kind, _, span) => MyError::Lit(kind, ControlFlow::Fatal, span),
+            MyError::Punct(_, span) => MyError::Punct(ControlFlow::Fatal, span),
+        }
+    }
+}
+
+impl IntoErrorKind<MyError> for LitKind {
+    fn into_error(self, control_flow: ControlFlow, span: Span) -> MyError {
+        MyError::Lit(self, control_flow, span)
+    }
+}
+
+type Input<'a> = TokenStream<'a, MyError>;
+
+#[test]
+fn test_custom_kind() {
+    assert_eq!(
+        next_if(|c: char| c.is_ascii_digit())
+            .map_err_kind(LitKind::Digit)
+            .parse(&mut Input::from("x")),
+        Err(MyError::Lit(
+            LitKind::Digit,
+            ControlFlow::Recovable,
+            Span::Range(0..1)
+        ))
+    );
+
+    assert_eq!(
+        next_if(|c: char| c.is_ascii_hexdigit())
+            .fatal()
+            .map_err_kind(LitKind::Hex)
+            .parse(&mut Input::from((3, "x"))),
+        Err(MyError::Lit(
+            LitKind::Hex,
+            ControlFlow::Fatal,
+            Span::Range(3..4)
+        ))
+    );
+
+    assert_eq!(
+        next_if(|c: char| c.is_ascii_digit())
+            .map_err_kind(LitKind::Digit)
+            .parse(&mut Input::from("1")),
+        Ok(Input::from("1"))
+    );
+}
+
+#[test]
+fn test_constructor_kind() {
+    assert_eq!(
+        next(';')
+            .map_err_kind(MyError::Punct)
+            .parse(&mut Input::from(",")),
+        Err(MyError::Punct(ControlFlow::Recovable, Span::Range(0..1)))
+    );
+
+    assert_eq!(
+        next(';').map_err_kind("semi").parse(&mut Input::from(",")),
+        Err(MyError::Kind(Kind::Syntax(
+            "semi",
+            ControlFlow::Recovable,
+            Span::Range(0..1)
+        )))
+    );
+}
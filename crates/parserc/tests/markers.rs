@@ -0,0 +1,87 @@
+mod common;
+
+use common::TokenStream;
+use parserc::{
+    ControlFlow, Expected, Input, Kind, LookBehind, LookBehindInput,
+    syntax::{Eof, LineStart, SyntaxInput, WordBoundary},
+};
+
+/// Returns the input advanced to `at`, with its previous item recorded.
+fn input_at(source: &str, at: usize) -> LookBehindInput<TokenStream<'_>> {
+    let mut input = LookBehindInput::new(TokenStream::from(source));
+    input.split_to(at);
+    input
+}
+
+#[test]
+fn test_word_boundary() {
+    let source = "ab cd\nef";
+
+    for at in [0, 2, 3, 5, 6, 8] {
+        let mut input = input_at(source, at);
+        assert!(
+            input.parse::<WordBoundary<_>>().is_ok(),
+            "expected a word boundary at {at}"
+        );
+        assert_eq!(input.start(), at);
+    }
+
+    for at in [1, 4, 7] {
+        let mut input = input_at(source, at);
+        assert!(matches!(
+            input.parse::<WordBoundary<_>>(),
+            Err(Kind::Expected {
+                what: Expected::Label("word boundary"),
+                control_flow: ControlFlow::Recovable,
+                ..
+            })
+        ));
+        assert_eq!(input.start(), at);
+    }
+}
+
+#[test]
+fn test_line_start() {
+    let source = "ab cd\nef";
+
+    for at in 0..=source.len() {
+        let mut input = input_at(source, at);
+        assert_eq!(
+            input.parse::<LineStart<_>>().is_ok(),
+            at == 0 || at == 6,
+            "line start at {at}"
+        );
+        assert_eq!(input.start(), at);
+    }
+}
+
+#[test]
+fn test_eof() {
+    let mut input = input_at("ab", 1);
+    assert_eq!(
+        input.parse::<Eof<_>>(),
+        Err(Kind::Eof(
+            ControlFlow::Recovable,
+            parserc::Span::Range(1..2)
+        ))
+    );
+    assert_eq!(input.start(), 1);
+
+    let mut input = input_at("ab", 2);
+    let eof = input.parse::<Eof<_>>().unwrap();
+    assert!(eof.0.is_empty());
+    assert_eq!(input.start(), 2);
+}
+
+#[test]
+fn test_prev_item() {
+    let mut input = LookBehindInput::new(TokenStream::from("ab\ncd"));
+    assert_eq!(input.prev_item(), None);
+
+    input.split_to(3);
+    assert_eq!(input.prev_item(), Some('\n'));
+
+    let tail = input.split_off(1);
+    assert_eq!(tail.prev_item(), Some('c'));
+    assert_eq!(input.prev_item(), Some('\n'));
+}
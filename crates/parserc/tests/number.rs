@@ -0,0 +1,84 @@
+mod common;
+
+use common::TokenStream;
+use parserc::{ControlFlow, Expected, Found, Kind, Parser, Span, chars, dec_int, dec_uint};
+
+#[test]
+fn test_dec_uint() {
+    let mut input = TokenStream::from("255;");
+    assert_eq!(dec_uint::<_, u8>().parse(&mut input), Ok(255));
+    assert_eq!(input, TokenStream::from((3, ";")));
+
+    let mut input = TokenStream::from("999");
+    assert_eq!(
+        dec_uint::<_, u8>().parse(&mut input),
+        Err(Kind::Overflow(ControlFlow::Fatal, Span::Range(0..3)))
+    );
+    assert_eq!(input, TokenStream::from("999"));
+
+    assert_eq!(
+        dec_uint::<_, u8>().parse(&mut TokenStream::from("x")),
+        Err(Kind::Expected {
+            what: Expected::Label("decimal digit"),
+            found: Found::Char('x'),
+            span: Span::Range(0..1),
+            control_flow: ControlFlow::Recovable,
+        })
+    );
+
+    assert_eq!(
+        dec_uint::<_, u8>().parse(&mut TokenStream::from("-1")),
+        Err(Kind::Expected {
+            what: Expected::Label("decimal digit"),
+            found: Found::Char('-'),
+            span: Span::Range(0..1),
+            control_flow: ControlFlow::Recovable,
+        })
+    );
+}
+
+#[test]
+fn test_dec_int() {
+    let mut input = TokenStream::from("-42)");
+    assert_eq!(dec_int::<_, i32>().parse(&mut input), Ok(-42));
+    assert_eq!(input, TokenStream::from((3, ")")));
+
+    assert_eq!(
+        dec_int::<_, i32>().parse(&mut TokenStream::from("+7")),
+        Ok(7)
+    );
+    assert_eq!(
+        dec_int::<_, i32>().parse(&mut TokenStream::from("2147483647")),
+        Ok(i32::MAX)
+    );
+    assert_eq!(
+        dec_int::<_, i32>().parse(&mut TokenStream::from("-2147483648")),
+        Ok(i32::MIN)
+    );
+    assert_eq!(
+        dec_int::<_, i32>().parse(&mut TokenStream::from("2147483648")),
+        Err(Kind::Overflow(ControlFlow::Fatal, Span::Range(0..10)))
+    );
+
+    let mut input = TokenStream::from("- 1");
+    assert_eq!(
+        dec_int::<_, i32>().parse(&mut input),
+        Err(Kind::Expected {
+            what: Expected::Label("decimal digit"),
+            found: Found::Char(' '),
+            span: Span::Range(0..2),
+            control_flow: ControlFlow::Recovable,
+        })
+    );
+    assert_eq!(input, TokenStream::from("- 1"));
+}
+
+#[test]
+fn test_bytes() {
+    let mut input = chars::TokenStream::<Kind>::from("12");
+    assert_eq!(dec_uint::<_, u64>().parse(&mut input), Ok(12));
+
+    let mut input = parserc::bytes::TokenStream::<Kind>::from("34ab");
+    assert_eq!(dec_uint::<_, u16>().parse(&mut input), Ok(34));
+    assert_eq!(input, parserc::bytes::TokenStream::from((2, "ab")));
+}
@@ -0,0 +1,34 @@
+mod common;
+
+use common::{Digits, Ident, TokenStream};
+use parserc::{
+    AsStr,
+    syntax::{Char, Or, SyntaxInput},
+};
+
+#[test]
+fn test_map_either() {
+    let value: Or<Ident<_>, Digits<_>> = TokenStream::from("abc").parse().unwrap();
+    assert_eq!(value.map_either(|v| v.0.as_str().len(), |_| 0), 3);
+
+    let value: Or<Ident<_>, Digits<_>> = TokenStream::from("42").parse().unwrap();
+    assert_eq!(
+        value.map_either(|_| 0, |v| v.0.as_str().parse::<usize>().unwrap()),
+        42
+    );
+}
+
+#[test]
+fn test_into_inner() {
+    let value: Or<Char<_, '+'>, Char<_, '-'>> = TokenStream::from("+").parse().unwrap();
+    assert_eq!(
+        value.map_either(|v| v.0, |v| v.0),
+        TokenStream::from((0, "+"))
+    );
+
+    let value: Or<Ident<_>, Ident<_>> = TokenStream::from("abc").parse().unwrap();
+    assert_eq!(value.into_inner(), Ident(TokenStream::from((0, "abc"))));
+
+    let value: Or<Ident<TokenStream<'_>>, _> = Or::Second(Ident(TokenStream::from((1, "b"))));
+    assert_eq!(value.into_inner(), Ident(TokenStream::from((1, "b"))));
+}
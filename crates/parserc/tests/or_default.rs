@@ -0,0 +1,54 @@
+mod common;
+
+use common::TokenStream;
+use parserc::{
+    AsStr, ControlFlow, Expected, Found, Kind, Parser, Span, next, take_while_range_from,
+};
+
+fn number() -> impl Parser<TokenStream<'static>, Output = u32> {
+    take_while_range_from(1, |c: char| c.is_ascii_digit())
+        .map(|digits: TokenStream<'static>| digits.as_str().parse().unwrap())
+}
+
+#[test]
+fn test_or_default() {
+    let mut input = TokenStream::from("42x");
+    assert_eq!(number().or_default().parse(&mut input), Ok(42));
+    assert_eq!(input, TokenStream::from((2, "x")));
+
+    assert_eq!(number().or_default().parse(&mut input), Ok(0));
+    assert_eq!(input, TokenStream::from((2, "x")));
+}
+
+#[test]
+fn test_or_default_fatal() {
+    let mut input = TokenStream::from("x");
+    assert_eq!(
+        next('a').fatal().map(|_| 1).or_default().parse(&mut input),
+        Err(Kind::Expected {
+            what: Expected::Char('a'),
+            found: Found::Char('x'),
+            span: Span::Range(0..1),
+            control_flow: ControlFlow::Fatal
+        })
+    );
+}
+
+#[test]
+fn test_or_value() {
+    let mut input = TokenStream::from("42x");
+    assert_eq!(number().or_value(7).parse(&mut input), Ok(42));
+    assert_eq!(input, TokenStream::from((2, "x")));
+
+    assert_eq!(number().or_value(7).parse(&mut input), Ok(7));
+    assert_eq!(input, TokenStream::from((2, "x")));
+
+    assert!(
+        next('a')
+            .fatal()
+            .map(|_| 1)
+            .or_value(7)
+            .parse(&mut input)
+            .is_err()
+    );
+}
@@ -0,0 +1,48 @@
+mod common;
+
+use common::{Ident, TokenStream};
+use parserc::{
+    ControlFlow, Kind, Span,
+    chars::{self, CharsInput},
+    syntax::{Char, Punctuated, Syntax},
+};
+
+#[derive(Debug, PartialEq, Syntax)]
+struct Path<I>
+where
+    I: CharsInput,
+{
+    pub segments: Punctuated<Ident<I>, Char<I, '.'>, false>,
+}
+
+#[test]
+fn test_parse_from_str() {
+    let ident: Ident<TokenStream> = Ident::parse_from_str("abc").unwrap();
+    assert_eq!(ident, Ident(TokenStream::from("abc")));
+
+    let path: Path<TokenStream> = Path::parse_from_str("a.b.c").unwrap();
+    assert_eq!(path.segments.len(), 3);
+    assert_eq!(path.to_span(), Span::Range(0..5));
+}
+
+#[test]
+fn test_leftover() {
+    assert_eq!(
+        Ident::<TokenStream>::parse_from_str("abc def"),
+        Err(Kind::Eof(ControlFlow::Recovable, Span::Range(3..7)))
+    );
+
+    assert_eq!(
+        Ident::<TokenStream>::parse_from_str(""),
+        Err(Kind::TakeWhileFrom(
+            ControlFlow::Recovable,
+            Span::Range(0..0)
+        ))
+    );
+}
+
+#[test]
+fn test_builtin_node() {
+    let comma: Char<chars::TokenStream<Kind>, ','> = Char::parse_from_str(",").unwrap();
+    assert_eq!(comma.to_span(), Span::Range(0..1));
+}
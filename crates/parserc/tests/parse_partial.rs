@@ -0,0 +1,48 @@
+mod common;
+
+use common::{Ident, TokenStream};
+use parserc::{
+    ControlFlow, Kind, Span,
+    syntax::{Char, Punctuated, parse_partial},
+};
+
+type Comma<I> = Char<I, ','>;
+
+#[test]
+fn test_trailing_content() {
+    assert_eq!(
+        parse_partial::<Ident<TokenStream>, _>("abc + 1"),
+        Ok((Ident(TokenStream::from((0, "abc"))), 3))
+    );
+
+    let (list, consumed) =
+        parse_partial::<Punctuated<Ident<TokenStream>, Comma<TokenStream>>, _>("a,b,c; rest")
+            .unwrap();
+
+    assert_eq!(list.len(), 3);
+    assert_eq!(consumed, 5);
+}
+
+#[test]
+fn test_multibyte() {
+    assert_eq!(
+        parse_partial::<Ident<TokenStream>, _>("ab中文"),
+        Ok((Ident(TokenStream::from((0, "ab"))), 2))
+    );
+
+    let (_, consumed) =
+        parse_partial::<(Ident<TokenStream>, Char<TokenStream, '中'>), _>("ab中文").unwrap();
+
+    assert_eq!(consumed, 5);
+}
+
+#[test]
+fn test_error() {
+    assert_eq!(
+        parse_partial::<Ident<TokenStream>, _>("1abc"),
+        Err(Kind::TakeWhileFrom(
+            ControlFlow::Recovable,
+            Span::Range(0..0)
+        ))
+    );
+}
@@ -0,0 +1,57 @@
+mod common;
+
+use common::TokenStream;
+use parserc::{ControlFlow, Kind, Parser, Span, next, permutation, take_while};
+
+fn field(c: char) -> impl Parser<TokenStream<'static>, Output = TokenStream<'static>> {
+    move |input: &mut TokenStream<'static>| {
+        take_while(|c: char| c == ' ').parse(input)?;
+        next(c).parse(input)
+    }
+}
+
+#[test]
+fn test_permutation() {
+    let mut input = TokenStream::from("b a c");
+
+    assert_eq!(
+        permutation((|| field('a'), || field('b'), || field('c'))).parse(&mut input),
+        Ok((
+            TokenStream::from((2, "a")),
+            TokenStream::from((0, "b")),
+            TokenStream::from((4, "c"))
+        ))
+    );
+    assert_eq!(input, TokenStream::from((5, "")));
+
+    let mut input = TokenStream::from("c b a!");
+
+    assert_eq!(
+        permutation((|| field('a'), || field('b'), || field('c'))).parse(&mut input),
+        Ok((
+            TokenStream::from((4, "a")),
+            TokenStream::from((2, "b")),
+            TokenStream::from((0, "c"))
+        ))
+    );
+    assert_eq!(input, TokenStream::from((5, "!")));
+}
+
+#[test]
+fn test_permutation_missing_or_duplicated() {
+    let mut input = TokenStream::from("b a");
+
+    assert_eq!(
+        permutation((|| field('a'), || field('b'), || field('c'))).parse(&mut input),
+        Err(Kind::Permutation(ControlFlow::Recovable, Span::Range(3..3)))
+    );
+    assert_eq!(input, TokenStream::from("b a"));
+
+    let mut input = TokenStream::from("b b c");
+
+    assert_eq!(
+        permutation((|| field('a'), || field('b'), || field('c'))).parse(&mut input),
+        Err(Kind::Permutation(ControlFlow::Recovable, Span::Range(1..2)))
+    );
+    assert_eq!(input, TokenStream::from("b b c"));
+}
@@ -0,0 +1,101 @@
+#![cfg(feature = "pratt")]
+
+mod common;
+
+use common::TokenStream;
+use parserc::{
+    AsStr, Parser, next, next_if,
+    pratt::{Assoc, Pratt},
+};
+
+fn calc() -> Pratt<'static, TokenStream<'static>, String> {
+    Pratt::new(|| {
+        next_if(|c: char| c.is_ascii_digit()).map(|d: TokenStream<'static>| d.as_str().to_owned())
+    })
+    .prefix(|| next('-'), 4, |_, v| format!("(-{})", v))
+    .infix(
+        || next('+'),
+        1,
+        Assoc::Left,
+        |l, _, r| format!("({}+{})", l, r),
+    )
+    .infix(
+        || next('-'),
+        1,
+        Assoc::Left,
+        |l, _, r| format!("({}-{})", l, r),
+    )
+    .infix(
+        || next('*'),
+        2,
+        Assoc::Left,
+        |l, _, r| format!("({}*{})", l, r),
+    )
+    .infix(
+        || next('^'),
+        3,
+        Assoc::Right,
+        |l, _, r| format!("({}^{})", l, r),
+    )
+    .postfix(|| next('!'), 5, |v, _| format!("({}!)", v))
+}
+
+#[test]
+fn test_precedence() {
+    let mut calc = calc();
+
+    for (source, expr) in [
+        ("1+2*3", "(1+(2*3))"),
+        ("1*2+3", "((1*2)+3)"),
+        ("1-2-3", "((1-2)-3)"),
+        ("2^3^2", "(2^(3^2))"),
+        ("-1+2", "((-1)+2)"),
+        ("-2^2", "((-2)^2)"),
+        ("2^-2", "(2^(-2))"),
+        ("1+2!*3", "(1+((2!)*3))"),
+        ("7", "7"),
+    ] {
+        assert_eq!(
+            (&mut calc).parse(&mut TokenStream::from(source)),
+            Ok(expr.to_owned()),
+            "{}",
+            source
+        );
+    }
+}
+
+#[test]
+fn test_rest() {
+    let mut input = TokenStream::from("1+2)");
+
+    assert_eq!(calc().parse(&mut input), Ok("(1+2)".to_owned()));
+    assert_eq!(input, TokenStream::from((3, ")")));
+
+    assert!(calc().parse(&mut TokenStream::from("1+")).is_err());
+}
+
+#[test]
+fn test_max_precedence() {
+    let mut calc = calc()
+        .infix(
+            || next('.'),
+            u32::MAX,
+            Assoc::Left,
+            |l, _, r| format!("({}.{})", l, r),
+        )
+        .postfix(|| next('?'), u32::MAX, |v, _| format!("({}?)", v));
+
+    for (source, expr) in [
+        ("1.2.3", "((1.2).3)"),
+        ("1+2.3", "(1+(2.3))"),
+        ("1.2?", "((1.2)?)"),
+        ("1.-2", "(1.(-2))"),
+    ] {
+        assert_eq!(
+            (&mut calc).parse(&mut TokenStream::from(source)),
+            Ok(expr.to_owned()),
+            "{}",
+            source
+        );
+    }
+}
@@ -0,0 +1,53 @@
+use parserc::predicates::*;
+
+#[test]
+fn test_ident() {
+    assert!(ident_start('_'));
+    assert!(ident_start('a'));
+    assert!(ident_start('中'));
+    assert!(!ident_start('1'));
+    assert!(!ident_start('-'));
+
+    assert!(ident_continue('_'));
+    assert!(ident_continue('1'));
+    assert!(ident_continue('中'));
+    assert!(!ident_continue('-'));
+    assert!(!ident_continue(' '));
+}
+
+#[test]
+fn test_digits() {
+    assert!(ascii_digit('0'));
+    assert!(ascii_digit('9'));
+    assert!(!ascii_digit('a'));
+    assert!(!ascii_digit('٣'));
+
+    assert!(hex_digit('9'));
+    assert!(hex_digit('a'));
+    assert!(hex_digit('F'));
+    assert!(!hex_digit('g'));
+    assert!(!hex_digit('_'));
+}
+
+#[test]
+fn test_whitespace() {
+    assert!(whitespace(' '));
+    assert!(whitespace('\t'));
+    assert!(whitespace('\n'));
+    assert!(whitespace('\u{3000}'));
+    assert!(!whitespace('_'));
+}
+
+#[test]
+fn test_alpha() {
+    assert!(alpha('a'));
+    assert!(alpha('Z'));
+    assert!(!alpha('1'));
+    assert!(!alpha('_'));
+    assert!(!alpha('é'));
+
+    assert!(alnum('a'));
+    assert!(alnum('1'));
+    assert!(!alnum('_'));
+    assert!(!alnum('é'));
+}
@@ -0,0 +1,154 @@
+mod common;
+
+use common::TokenStream;
+use parserc::{
+    ControlFlow, Kind, Parser, Span, between, collect_into, collect_separated_spans,
+    collect_string, count, delimited_list, fold_left, next, sep_end_by0,
+    syntax::{Char, Punctuated, SeparatedNonEmpty, SyntaxInput},
+    take_while,
+};
+
+type Empty<'a> = Option<Char<TokenStream<'a>, 'a'>>;
+
+#[test]
+fn test_vec_no_progress() {
+    let mut input = TokenStream::from("aab");
+
+    assert_eq!(
+        input.parse::<Vec<Empty>>(),
+        Err(Kind::NoProgress(ControlFlow::Fatal, Span::Range(2..2)))
+    );
+
+    assert_eq!(
+        TokenStream::from("aab")
+            .parse::<Vec<Char<_, 'a'>>>()
+            .map(|v| v.len()),
+        Ok(2)
+    );
+}
+
+#[test]
+fn test_punctuated_no_progress() {
+    assert_eq!(
+        TokenStream::from("b").parse::<Punctuated<Empty, Option<Char<_, ','>>>>(),
+        Err(Kind::NoProgress(ControlFlow::Fatal, Span::Range(0..0)))
+    );
+}
+
+#[test]
+fn test_ensure_progress() {
+    let mut input = TokenStream::from("aab");
+
+    assert_eq!(
+        count(|| take_while(|c: char| c == 'a').ensure_progress()).parse(&mut input),
+        Err(Kind::NoProgress(ControlFlow::Fatal, Span::Range(2..2)))
+    );
+
+    let mut input = TokenStream::from("aab");
+    assert_eq!(
+        count(|| next('a').ensure_progress()).parse(&mut input),
+        Ok(2)
+    );
+    assert_eq!(input, TokenStream::from((2, "b")));
+}
+
+#[test]
+fn test_repetitions_no_progress() {
+    let no_progress = Err(Kind::NoProgress(ControlFlow::Fatal, Span::Range(2..2)));
+    let a = || take_while(|c: char| c == 'a');
+
+    assert_eq!(count(a).parse(&mut TokenStream::from("aab")), no_progress);
+
+    let mut buf = vec![];
+    assert_eq!(
+        collect_into(&mut buf, a).parse(&mut TokenStream::from("aab")),
+        no_progress
+    );
+
+    assert_eq!(
+        collect_string(a)
+            .parse(&mut TokenStream::from("aab"))
+            .map(|s| s.len()),
+        no_progress
+    );
+
+    assert_eq!(
+        between(0, usize::MAX, a)
+            .parse(&mut TokenStream::from("aab"))
+            .map(|v| v.len()),
+        no_progress
+    );
+
+    assert_eq!(
+        sep_end_by0(a, || take_while(|c: char| c == ';'))
+            .parse(&mut TokenStream::from("a;b"))
+            .map(|v| v.len()),
+        no_progress
+    );
+}
+
+#[test]
+fn test_separated_non_empty_no_progress() {
+    assert_eq!(
+        TokenStream::from("!")
+            .parse::<SeparatedNonEmpty<Empty, Option<Char<_, ','>>>>()
+            .map(|v| v.rest.len()),
+        Err(Kind::NoProgress(ControlFlow::Fatal, Span::Range(0..0)))
+    );
+}
+
+#[test]
+fn test_sequences_no_progress() {
+    let digits = || take_while(|c: char| c.is_ascii_digit());
+
+    assert_eq!(
+        delimited_list(
+            next('['),
+            digits,
+            || take_while(|c: char| c == ','),
+            next(']')
+        )
+        .parse(&mut TokenStream::from("[x]"))
+        .map(|(_, v, _)| v.len()),
+        Err(Kind::NoProgress(ControlFlow::Fatal, Span::Range(1..1)))
+    );
+
+    assert_eq!(
+        fold_left(digits, || take_while(|c: char| c == '+'), |lhs, _, _| lhs)
+            .parse(&mut TokenStream::from("1x")),
+        Err(Kind::NoProgress(ControlFlow::Fatal, Span::Range(1..1)))
+    );
+
+    assert_eq!(
+        collect_separated_spans(digits, || take_while(|c: char| c == ','))
+            .parse(&mut TokenStream::from("1x")),
+        Err(Kind::NoProgress(ControlFlow::Fatal, Span::Range(1..1)))
+    );
+}
+
+#[cfg(feature = "pratt")]
+#[test]
+fn test_pratt_no_progress() {
+    use parserc::pratt::{Assoc, Pratt};
+
+    let operand = || next('1').map(|_| 1);
+
+    assert_eq!(
+        Pratt::new(operand)
+            .postfix(|| take_while(|c: char| c == '!'), 1, |v, _| v)
+            .parse(&mut TokenStream::from("1x")),
+        Err(Kind::NoProgress(ControlFlow::Fatal, Span::Range(1..1)))
+    );
+
+    assert_eq!(
+        Pratt::new(|| take_while(|c: char| c == '1').map(|_| 1))
+            .infix(
+                || take_while(|c: char| c == '+'),
+                1,
+                Assoc::Left,
+                |l, _, r| l + r
+            )
+            .parse(&mut TokenStream::from("1x")),
+        Err(Kind::NoProgress(ControlFlow::Fatal, Span::Range(1..1)))
+    );
+}
@@ -0,0 +1,169 @@
+mod common;
+
+use common::{Ident, TokenStream};
+use parserc::{
+    AsStr, ControlFlow, Kind, Span,
+    syntax::{Char, Punctuated, SeparatedNonEmpty, SyntaxInput},
+};
+
+type Comma<I> = Char<I, ','>;
+
+#[test]
+fn test_has_trailing() {
+    let mut input = TokenStream::from("a,b");
+    let punctuated: Punctuated<Ident<_>, Comma<_>> = input.parse().unwrap();
+    assert_eq!(punctuated.len(), 2);
+    assert!(!punctuated.has_trailing());
+
+    let mut input = TokenStream::from("a,b,");
+    let punctuated: Punctuated<Ident<_>, Comma<_>> = input.parse().unwrap();
+    assert_eq!(punctuated.len(), 2);
+    assert!(punctuated.has_trailing());
+
+    let mut input = TokenStream::from("");
+    let punctuated: Punctuated<Ident<_>, Comma<_>> = input.parse().unwrap();
+    assert!(punctuated.is_empty());
+    assert!(!punctuated.has_trailing());
+}
+
+#[test]
+fn test_double_separator() {
+    let mut input = TokenStream::from("a,,b");
+    assert!(matches!(
+        input.parse::<Punctuated<Ident<_>, Comma<_>>>(),
+        Err(Kind::Punctuated(ControlFlow::Fatal, _))
+    ));
+
+    let mut input = TokenStream::from("a,,b");
+    assert!(matches!(
+        input.parse::<Punctuated<Ident<_>, Comma<_>, false>>(),
+        Err(Kind::Punctuated(ControlFlow::Fatal, _))
+    ));
+}
+
+#[test]
+fn test_trailing_separator_then_garbage() {
+    let mut input = TokenStream::from("a,b,!");
+    let punctuated: Punctuated<Ident<_>, Comma<_>> = input.parse().unwrap();
+    assert_eq!(punctuated.len(), 2);
+    assert!(punctuated.has_trailing());
+    assert_eq!(input.as_str(), "!");
+
+    let mut input = TokenStream::from("a,b,!");
+    assert_eq!(
+        input.parse::<Punctuated<Ident<_>, Comma<_>, false>>(),
+        Err(Kind::Punctuated(ControlFlow::Fatal, Span::Range(4..5)))
+    );
+}
+
+#[test]
+fn test_iter() {
+    let mut input = TokenStream::from("a,b,c");
+    let mut punctuated: Punctuated<Ident<_>, Comma<_>> = input.parse().unwrap();
+
+    let idents = punctuated
+        .iter()
+        .map(|ident| ident.0.as_str())
+        .collect::<Vec<_>>();
+    assert_eq!(idents, ["a", "b", "c"]);
+
+    let seps = punctuated
+        .pairs()
+        .map(|(_, p)| p.is_some())
+        .collect::<Vec<_>>();
+    assert_eq!(seps, [true, true, false]);
+
+    assert_eq!(punctuated.iter_mut().count(), 3);
+    assert_eq!((&punctuated).into_iter().count(), 3);
+
+    let idents = punctuated
+        .into_iter()
+        .map(|ident| ident.0.as_str().to_owned())
+        .collect::<Vec<_>>();
+    assert_eq!(idents, ["a", "b", "c"]);
+
+    let mut input = TokenStream::from("a,b,c,");
+    let punctuated: Punctuated<Ident<_>, Comma<_>> = input.parse().unwrap();
+
+    let idents = punctuated
+        .iter()
+        .map(|ident| ident.0.as_str())
+        .collect::<Vec<_>>();
+    assert_eq!(idents, ["a", "b", "c"]);
+
+    let seps = punctuated
+        .pairs()
+        .map(|(_, p)| p.is_some())
+        .collect::<Vec<_>>();
+    assert_eq!(seps, [true, true, true]);
+
+    let idents = punctuated
+        .into_iter()
+        .map(|ident| ident.0.as_str().to_owned())
+        .collect::<Vec<_>>();
+    assert_eq!(idents, ["a", "b", "c"]);
+}
+
+#[test]
+fn test_build() {
+    let mut punctuated = Punctuated::<Ident<TokenStream<'_>>, Comma<_>>::new();
+    punctuated.push_value(Ident(TokenStream::from((0, "a"))));
+    punctuated.push_punct(Char(TokenStream::from((1, ","))));
+    punctuated.push_value(Ident(TokenStream::from((2, "b"))));
+
+    let mut input = TokenStream::from("a,b");
+    assert_eq!(input.parse(), Ok(punctuated));
+
+    let punctuated: Punctuated<Ident<TokenStream<'_>>, Comma<_>> = [
+        (
+            Ident(TokenStream::from((0, "a"))),
+            Char(TokenStream::from((1, ","))),
+        ),
+        (
+            Ident(TokenStream::from((2, "b"))),
+            Char(TokenStream::from((3, ","))),
+        ),
+    ]
+    .into_iter()
+    .collect();
+
+    let mut input = TokenStream::from("a,b,");
+    assert_eq!(input.parse(), Ok(punctuated));
+}
+
+#[test]
+#[should_panic]
+fn test_push_value_twice() {
+    let mut punctuated = Punctuated::<Ident<TokenStream<'_>>, Comma<TokenStream<'_>>>::default();
+    punctuated.push_value(Ident(TokenStream::from((0, "a"))));
+    punctuated.push_value(Ident(TokenStream::from((1, "b"))));
+}
+
+#[test]
+#[should_panic]
+fn test_push_punct_first() {
+    let mut punctuated = Punctuated::<Ident<TokenStream<'_>>, Comma<TokenStream<'_>>>::default();
+    punctuated.push_punct(Char(TokenStream::from((0, ","))));
+}
+
+#[test]
+fn test_separated_non_empty() {
+    let mut input = TokenStream::from("a,b,!");
+    let separated: SeparatedNonEmpty<Ident<_>, Comma<_>> = input.parse().unwrap();
+    assert_eq!(
+        separated
+            .iter()
+            .map(|ident| ident.0.as_str())
+            .collect::<Vec<_>>(),
+        ["a", "b"]
+    );
+    assert_eq!(separated.last().0.as_str(), "b");
+    assert_eq!(input.as_str(), ",!");
+
+    let mut input = TokenStream::from(",a");
+    assert!(
+        input
+            .parse::<SeparatedNonEmpty<Ident<_>, Comma<_>>>()
+            .is_err()
+    );
+}
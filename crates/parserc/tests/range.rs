@@ -0,0 +1,82 @@
+mod common;
+
+use std::ops::{Range, RangeInclusive};
+
+use common::TokenStream;
+use parserc::{
+    ControlFlow, Expected, Found, Kind, Parser, Span, chars, dec_uint,
+    syntax::{RangeInclusiveOf, RangeOf, Syntax, SyntaxInput, ToSource},
+};
+
+#[derive(Debug, PartialEq, Syntax)]
+#[parserc(display, keyword = "..")]
+struct DotDot<I>(pub I)
+where
+    I: chars::CharsInput;
+
+#[derive(Debug, PartialEq, Syntax)]
+#[parserc(display, keyword = "..=")]
+struct DotDotEq<I>(pub I)
+where
+    I: chars::CharsInput;
+
+/// A decimal `u32` keeping its span.
+#[derive(Debug, PartialEq)]
+struct Num(u32, Span);
+
+impl<'a> Syntax<TokenStream<'a>> for Num {
+    fn parse(input: &mut TokenStream<'a>) -> Result<Self, Kind> {
+        let start = input.offset;
+        let value = dec_uint().parse(input)?;
+        Ok(Self(value, Span::Range(start..input.offset)))
+    }
+
+    fn to_span(&self) -> Span {
+        self.1.clone()
+    }
+}
+
+impl ToSource for Num {
+    fn to_source(&self, out: &mut String) {
+        out.push_str(&self.0.to_string());
+    }
+}
+
+#[test]
+fn test_range() {
+    let range = TokenStream::from("1..5")
+        .parse::<RangeOf<Num, DotDot<_>>>()
+        .unwrap();
+
+    assert_eq!(range.to_span(), Span::Range(0..4));
+    assert_eq!(range.sep, DotDot(TokenStream::from((1, ".."))));
+
+    let mut out = String::new();
+    range.to_source(&mut out);
+    assert_eq!(out, "1..5");
+
+    let range: Range<Num> = range.into();
+    assert_eq!(range.start.0..range.end.0, 1u32..5);
+
+    assert_eq!(
+        TokenStream::from("1-").parse::<RangeOf<Num, DotDot<_>>>(),
+        Err(Kind::Expected {
+            what: Expected::Keyword("..".into()),
+            found: Found::Char('-'),
+            span: Span::Range(1..2),
+            control_flow: ControlFlow::Recovable,
+        })
+    );
+}
+
+#[test]
+fn test_range_inclusive() {
+    let range = TokenStream::from("10..=20")
+        .parse::<RangeInclusiveOf<Num, DotDotEq<_>>>()
+        .unwrap();
+
+    assert_eq!(range.to_span(), Span::Range(0..7));
+
+    let range: RangeInclusive<Num> = range.into();
+    assert_eq!(range.start().0..=range.end().0, 10u32..=20);
+}
@@ -0,0 +1,74 @@
+mod common;
+
+use common::TokenStream;
+use parserc::{ControlFlow, Expected, Found, Kind, Parser, Span, keyword, next, take_while};
+
+/// Parses `let <name>;`, returning the name.
+fn stmt<'a>(input: &mut TokenStream<'a>) -> Result<TokenStream<'a>, Kind> {
+    keyword("let ").parse(input)?;
+    let name = take_while(|c: char| c.is_ascii_alphabetic()).parse(input)?;
+    next(';').parse(input)?;
+    Ok(name)
+}
+
+#[test]
+fn test_recover_to() {
+    let mut input = TokenStream::from("let a;let b = 1;let c;");
+
+    assert_eq!(
+        stmt.recover_to(";").parse(&mut input),
+        Ok(Ok(TokenStream::from((4, "a"))))
+    );
+
+    assert_eq!(
+        stmt.recover_to(";").parse(&mut input),
+        Ok(Err(Kind::Expected {
+            what: Expected::Char(';'),
+            found: Found::Char(' '),
+            span: Span::Range(11..12),
+            control_flow: ControlFlow::Recovable,
+        }))
+    );
+    assert_eq!(input, TokenStream::from((16, "let c;")));
+
+    assert_eq!(
+        stmt.recover_to(";").parse(&mut input),
+        Ok(Ok(TokenStream::from((20, "c"))))
+    );
+    assert!(input.value.is_empty());
+}
+
+#[test]
+fn test_recover_to_end() {
+    let mut input = TokenStream::from("let b = 1");
+
+    assert!(stmt.recover_to(";").parse(&mut input).unwrap().is_err());
+    assert_eq!(input, TokenStream::from((9, "")));
+}
+
+#[test]
+fn test_recover_to_fatal() {
+    let mut input = TokenStream::from("let b = 1;");
+
+    assert_eq!(
+        stmt.fatal().recover_to(";").parse(&mut input),
+        Err(Kind::Expected {
+            what: Expected::Char(';'),
+            found: Found::Char(' '),
+            span: Span::Range(5..6),
+            control_flow: ControlFlow::Fatal,
+        })
+    );
+
+    let mut input = TokenStream::from("let b = 1;");
+
+    assert!(
+        stmt.fatal()
+            .recoverable()
+            .recover_to(";")
+            .parse(&mut input)
+            .unwrap()
+            .is_err()
+    );
+    assert!(input.value.is_empty());
+}
@@ -0,0 +1,63 @@
+mod common;
+
+use common::TokenStream;
+use parserc::{ControlFlow, ParseError, Parser, next, syntax::Recursive};
+
+/// parens := '(' parens* ')', returns the max nesting depth.
+fn balanced_parens() -> Recursive<TokenStream<'static>, usize> {
+    let parens = Recursive::declare();
+
+    let inner = parens.clone();
+
+    parens.define(move || {
+        let inner = inner.clone();
+
+        move |input: &mut TokenStream<'static>| {
+            next('(').parse(input)?;
+
+            let mut depth = 0;
+
+            while let Some(child) = inner.clone().ok().parse(input)? {
+                depth = depth.max(child);
+            }
+
+            next(')').fatal().parse(input)?;
+
+            Ok(depth + 1)
+        }
+    });
+
+    parens
+}
+
+#[test]
+fn test_balanced_parens() {
+    let parens = balanced_parens();
+
+    let mut input = TokenStream::from("(()(()))");
+    assert_eq!(parens.clone().parse(&mut input), Ok(3));
+    assert_eq!(input, TokenStream::from((8, "")));
+
+    let mut input = TokenStream::from("()");
+    assert_eq!(parens.clone().parse(&mut input), Ok(1));
+
+    let mut input = TokenStream::from("(()");
+    assert!(matches!(
+        parens.clone().parse(&mut input),
+        Err(err) if err.control_flow() == ControlFlow::Fatal
+    ));
+
+    let mut input = TokenStream::from(")");
+    assert!(matches!(
+        parens.parse(&mut input),
+        Err(err) if err.control_flow() == ControlFlow::Recovable
+    ));
+}
+
+#[test]
+#[should_panic]
+fn test_undefined() {
+    let parens = Recursive::<TokenStream<'static>, usize>::declare();
+
+    _ = parens.parse(&mut TokenStream::from("()"));
+}
@@ -0,0 +1,164 @@
+mod common;
+
+use common::TokenStream;
+use parserc::{
+    AsStr, ControlFlow, Expected, Found, Kind, Parser, Span, between, collect_into, collect_string,
+    count, next, sep_end_by0, sep_end_by1,
+};
+
+#[test]
+fn test_collect_into() {
+    let mut buf = vec![];
+
+    let mut input = TokenStream::from("aab");
+    assert_eq!(
+        collect_into(&mut buf, || next('a')).parse(&mut input),
+        Ok(2)
+    );
+    assert_eq!(input, TokenStream::from((2, "b")));
+
+    let mut input = TokenStream::from("b");
+    assert_eq!(
+        collect_into(&mut buf, || next('b')).parse(&mut input),
+        Ok(1)
+    );
+
+    let mut input = TokenStream::from("c");
+    assert_eq!(
+        collect_into(&mut buf, || next('a')).parse(&mut input),
+        Ok(0)
+    );
+
+    assert_eq!(
+        buf,
+        [
+            TokenStream::from((0, "a")),
+            TokenStream::from((1, "a")),
+            TokenStream::from((0, "b"))
+        ]
+    );
+}
+
+#[test]
+fn test_sep_end_by() {
+    let mut input = TokenStream::from("a;b;");
+    assert_eq!(
+        sep_end_by0(|| next('a').or(next('b')), || next(';')).parse(&mut input),
+        Ok(vec![
+            TokenStream::from((0, "a")),
+            TokenStream::from((2, "b"))
+        ])
+    );
+    assert_eq!(input, TokenStream::from((4, "")));
+
+    let mut input = TokenStream::from("a;b");
+    assert_eq!(
+        sep_end_by0(|| next('a').or(next('b')), || next(';')).parse(&mut input),
+        Ok(vec![TokenStream::from((0, "a"))])
+    );
+    assert_eq!(input, TokenStream::from((2, "b")));
+
+    let mut input = TokenStream::from("a;b");
+    assert_eq!(
+        sep_end_by1(|| next('a').or(next('b')), || next(';')).parse(&mut input),
+        Ok(vec![TokenStream::from((0, "a"))])
+    );
+
+    let mut input = TokenStream::from("b");
+    assert_eq!(
+        sep_end_by0(|| next('b'), || next(';')).parse(&mut input),
+        Ok(vec![])
+    );
+    assert_eq!(input, TokenStream::from("b"));
+    assert!(
+        sep_end_by1(|| next('b'), || next(';'))
+            .parse(&mut input)
+            .is_err()
+    );
+    assert_eq!(input, TokenStream::from("b"));
+}
+
+#[test]
+fn test_between() {
+    let mut input = TokenStream::from("aab");
+    assert_eq!(
+        between(3, 4, || next('a')).parse(&mut input),
+        Err(Kind::Between(ControlFlow::Recovable, Span::Range(0..2)))
+    );
+    assert_eq!(input, TokenStream::from("aab"));
+
+    assert_eq!(
+        between(1, 4, || next('a')).parse(&mut input),
+        Ok(vec![
+            TokenStream::from((0, "a")),
+            TokenStream::from((1, "a"))
+        ])
+    );
+    assert_eq!(input, TokenStream::from((2, "b")));
+
+    let mut input = TokenStream::from("aaaa");
+    assert_eq!(
+        between(1, 3, || next('a'))
+            .parse(&mut input)
+            .map(|v| v.len()),
+        Ok(3)
+    );
+    assert_eq!(input, TokenStream::from((3, "a")));
+
+    let mut input = TokenStream::from("b");
+    assert_eq!(between(0, 3, || next('a')).parse(&mut input), Ok(vec![]));
+}
+
+#[test]
+#[cfg(debug_assertions)]
+#[should_panic(expected = "`between` with `min` 3 > `max` 1")]
+fn test_between_min_above_max() {
+    let _ = between(3, 1, || next('a')).parse(&mut TokenStream::from("aaa"));
+}
+
+#[test]
+fn test_count() {
+    let mut input = TokenStream::from("###title");
+    assert_eq!(count(|| next('#')).parse(&mut input), Ok(3));
+    assert_eq!(input, TokenStream::from((3, "title")));
+
+    let mut input = TokenStream::from("title");
+    assert_eq!(count(|| next('#')).parse(&mut input), Ok(0));
+    assert_eq!(input, TokenStream::from("title"));
+
+    assert_eq!(
+        count(|| next('#').fatal()).parse(&mut TokenStream::from("#a")),
+        Err(Kind::Expected {
+            what: Expected::Char('#'),
+            found: Found::Char('a'),
+            span: Span::Range(1..2),
+            control_flow: ControlFlow::Fatal,
+        })
+    );
+}
+
+#[test]
+fn test_collect_string() {
+    let mut input = TokenStream::from("abc!");
+    assert_eq!(
+        collect_string(|| next('a')
+            .or(next('b'))
+            .or(next('c'))
+            .map(|c: TokenStream| { c.as_str().chars().next().unwrap() }))
+        .parse(&mut input),
+        Ok("abc".to_string())
+    );
+    assert_eq!(input, TokenStream::from((3, "!")));
+
+    let mut input = TokenStream::from("aab");
+    assert_eq!(
+        collect_string(|| next('a')).parse(&mut input),
+        Ok("aa".to_string())
+    );
+    assert_eq!(input, TokenStream::from((2, "b")));
+
+    assert_eq!(
+        collect_string(|| next('a')).parse(&mut TokenStream::from("b")),
+        Ok(String::new())
+    );
+}
@@ -0,0 +1,50 @@
+mod common;
+
+use common::TokenStream;
+use parserc::{Parser, split_on};
+
+#[test]
+fn test_split_on() {
+    let mut input = TokenStream::from("a::b::c");
+
+    assert_eq!(
+        split_on("::").parse(&mut input),
+        Ok(vec![
+            TokenStream::from((0, "a")),
+            TokenStream::from((3, "b")),
+            TokenStream::from((6, "c")),
+        ])
+    );
+
+    assert_eq!(input, TokenStream::from((7, "")));
+}
+
+#[test]
+fn test_empty_segments() {
+    assert_eq!(
+        split_on("::").parse(&mut TokenStream::from("::a::::b::")),
+        Ok(vec![
+            TokenStream::from((0, "")),
+            TokenStream::from((2, "a")),
+            TokenStream::from((5, "")),
+            TokenStream::from((7, "b")),
+            TokenStream::from((10, "")),
+        ])
+    );
+
+    assert_eq!(
+        split_on(",").parse(&mut TokenStream::from((4, ""))),
+        Ok(vec![TokenStream::from((4, ""))])
+    );
+
+    assert_eq!(
+        split_on(",").parse(&mut TokenStream::from("中文")),
+        Ok(vec![TokenStream::from("中文")])
+    );
+}
+
+#[test]
+#[should_panic]
+fn test_empty_separator() {
+    _ = split_on("").parse(&mut TokenStream::from("ab"));
+}
@@ -0,0 +1,78 @@
+mod common;
+
+use common::{Ident, TokenStream};
+use parserc::{
+    ControlFlow, Expected, Found, Kind, Span,
+    chars::CharsInput,
+    syntax::{Char, Preceded, Surrounded, Syntax, SyntaxInput, Terminated, ToSource},
+};
+
+type Attr<I> = Surrounded<Char<I, '('>, Ident<I>, Char<I, ')'>>;
+
+/// `name;` with a `#`-prefixed attribute in parentheses, e.g. `#(inline)x;`.
+#[derive(Debug, PartialEq, Syntax)]
+struct Stmt<I>
+where
+    I: CharsInput,
+{
+    attr: Preceded<Char<I, '#'>, Attr<I>>,
+    name: Terminated<Ident<I>, Char<I, ';'>>,
+}
+
+#[test]
+fn test_preceded() {
+    let mut input = TokenStream::from("#a");
+    let value = input.parse::<Preceded<Char<_, '#'>, Ident<_>>>().unwrap();
+
+    assert_eq!(*value, Ident(TokenStream::from((1, "a"))));
+    assert_eq!(value.to_span(), Span::Range(0..2));
+    assert_eq!(value.into_inner(), Ident(TokenStream::from((1, "a"))));
+
+    assert!(
+        TokenStream::from("a")
+            .parse::<Preceded<Char<_, '#'>, Ident<_>>>()
+            .is_err()
+    );
+}
+
+#[test]
+fn test_terminated() {
+    let mut input = TokenStream::from("abc;");
+    let value = input.parse::<Terminated<Ident<_>, Char<_, ';'>>>().unwrap();
+
+    assert_eq!(value.0, TokenStream::from("abc"));
+    assert_eq!(value.to_span(), Span::Range(0..4));
+
+    let mut out = String::new();
+    value.to_source(&mut out);
+    assert_eq!(out, "abc;");
+}
+
+#[test]
+fn test_surrounded() {
+    let value = TokenStream::from("(a)")
+        .parse::<Surrounded<Char<_, '('>, Ident<_>, Char<_, ')'>>>()
+        .unwrap();
+
+    assert_eq!(value.to_span(), Span::Range(0..3));
+    assert_eq!(value.into_inner().0, TokenStream::from((1, "a")));
+
+    assert_eq!(
+        TokenStream::from("(a").parse::<Surrounded<Char<_, '('>, Ident<_>, Char<_, ')'>>>(),
+        Err(Kind::Expected {
+            what: Expected::Char(')'),
+            found: Found::Eof,
+            span: Span::Range(2..2),
+            control_flow: ControlFlow::Fatal,
+        })
+    );
+}
+
+#[test]
+fn test_derived_fields() {
+    let stmt = TokenStream::from("#(inline)x;").parse::<Stmt<_>>().unwrap();
+
+    assert_eq!(stmt.attr.0, TokenStream::from((2, "inline")));
+    assert_eq!(stmt.name.0, TokenStream::from((9, "x")));
+    assert_eq!(stmt.to_span(), Span::Range(0..11));
+}
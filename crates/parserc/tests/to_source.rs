@@ -0,0 +1,42 @@
+mod common;
+
+use common::{Ident, TokenStream};
+use parserc::syntax::{Char, Delimiter, Or, Punctuated, SyntaxInput, ToSource};
+
+type Comma<I> = Char<I, ','>;
+
+type List<I> = Delimiter<Char<I, '['>, Char<I, ']'>, Punctuated<Ident<I>, Comma<I>>>;
+
+fn to_source<T: ToSource>(node: &T) -> String {
+    let mut out = String::new();
+    node.to_source(&mut out);
+    out
+}
+
+#[test]
+fn test_punctuated() {
+    for source in ["", "a", "a,b", "a,b,"] {
+        let punctuated: Punctuated<Ident<_>, Comma<_>> = TokenStream::from(source).parse().unwrap();
+
+        assert_eq!(to_source(&punctuated), source);
+    }
+}
+
+#[test]
+fn test_delimiter() {
+    for source in ["[]", "[a]", "[a,bc,]"] {
+        let list: List<_> = TokenStream::from(source).parse().unwrap();
+
+        assert_eq!(to_source(&list), source);
+    }
+}
+
+#[test]
+fn test_nested() {
+    let source = "x[a,b]";
+
+    let node: Vec<(Option<Ident<_>>, Or<List<_>, Comma<_>>)> =
+        TokenStream::from(source).parse().unwrap();
+
+    assert_eq!(to_source(&node), source);
+}
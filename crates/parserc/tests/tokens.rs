@@ -0,0 +1,54 @@
+mod common;
+
+use common::TokenStream;
+use parserc::{ControlFlow, Kind, Parser, Span, take_while, take_while_range_from, tokens};
+
+fn ident<'a>() -> impl Parser<TokenStream<'a>, Output = TokenStream<'a>> {
+    |input: &mut TokenStream<'a>| {
+        take_while(|c: char| c == ' ').parse(input)?;
+        take_while_range_from(1, |c: char| c.is_ascii_alphabetic()).parse(input)
+    }
+}
+
+#[test]
+fn test_tokens() {
+    let iter = tokens(TokenStream::from("a b c"), ident);
+
+    assert_eq!(
+        iter.collect::<Result<Vec<_>, _>>(),
+        Ok(vec![
+            TokenStream::from((0, "a")),
+            TokenStream::from((2, "b")),
+            TokenStream::from((4, "c")),
+        ])
+    );
+
+    assert_eq!(tokens(TokenStream::from(""), ident).next(), None);
+}
+
+#[test]
+fn test_error_item() {
+    let mut iter = tokens(TokenStream::from("a 1"), ident);
+
+    assert_eq!(iter.next(), Some(Ok(TokenStream::from((0, "a")))));
+    assert_eq!(
+        iter.next(),
+        Some(Err(Kind::TakeWhileFrom(
+            ControlFlow::Recovable,
+            Span::Range(2..2)
+        )))
+    );
+    assert_eq!(iter.next(), None);
+    assert_eq!(iter.into_input(), TokenStream::from((2, "1")));
+}
+
+#[test]
+fn test_no_progress() {
+    let mut iter = tokens(TokenStream::from("1"), || {
+        take_while(|c: char| c.is_ascii_alphabetic())
+    });
+
+    assert_eq!(iter.next(), Some(Ok(TokenStream::from((0, "")))));
+    assert_eq!(iter.next(), None);
+    assert_eq!(iter.input(), &TokenStream::from((0, "1")));
+}
@@ -0,0 +1,62 @@
+mod common;
+
+use common::TokenStream;
+use parserc::{ControlFlow, Kind, Parser, Span, keyword, take_while_range_from};
+
+fn char_7bit<'a>() -> impl Parser<TokenStream<'a>, Output = char> {
+    |input: &mut TokenStream<'a>| {
+        keyword("\\x").parse(input)?;
+
+        take_while_range_from(1, |c: char| c.is_ascii_hexdigit())
+            .verify_map(|digits: TokenStream<'a>| {
+                u8::from_str_radix(digits.value, 16)
+                    .ok()
+                    .filter(u8::is_ascii)
+                    .map(char::from)
+            })
+            .parse(input)
+    }
+}
+
+#[test]
+fn test_some() {
+    let mut input = TokenStream::from("\\x41z");
+
+    assert_eq!(char_7bit().parse(&mut input), Ok('A'));
+    assert_eq!(input, TokenStream::from((4, "z")));
+}
+
+#[test]
+fn test_none() {
+    let mut input = TokenStream::from("\\x80z");
+
+    assert_eq!(
+        char_7bit().parse(&mut input),
+        Err(Kind::Verify(ControlFlow::Recovable, Span::Range(2..4)))
+    );
+    assert_eq!(input, TokenStream::from((2, "80z")));
+
+    let mut input = TokenStream::from("1234");
+
+    assert_eq!(
+        take_while_range_from(1, |c: char| c.is_ascii_digit())
+            .verify_map(|digits: TokenStream| digits.value.parse::<u8>().ok())
+            .ok()
+            .parse(&mut input),
+        Ok(None)
+    );
+    assert_eq!(input, TokenStream::from("1234"));
+}
+
+#[test]
+fn test_inner_error() {
+    assert_eq!(
+        take_while_range_from(1, |c: char| c.is_ascii_digit())
+            .verify_map(|_: TokenStream| Some(()))
+            .parse(&mut TokenStream::from("x")),
+        Err(Kind::TakeWhileFrom(
+            ControlFlow::Recovable,
+            Span::Range(0..0)
+        ))
+    );
+}
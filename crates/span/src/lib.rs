@@ -1,7 +1,8 @@
 //！ `span` is a region of source code
 #![cfg_attr(docsrs, feature(doc_cfg))]
+#![no_std]
 
-use std::{
+use core::{
     cmp,
     ops::{self, Range, RangeFrom, RangeFull, RangeTo},
 };
@@ -28,6 +29,41 @@ impl<Idx> From<Range<Idx>> for Span<Idx> {
     }
 }
 
+impl<Idx> From<(Idx, Idx)> for Span<Idx> {
+    /// Creates `Span::Range(start..end)` from `(start, end)`.
+    fn from((start, end): (Idx, Idx)) -> Self {
+        Self::Range(start..end)
+    }
+}
+
+impl From<usize> for Span<usize> {
+    /// Creates a zero-length span at `offset`, see [`Span::point`].
+    fn from(offset: usize) -> Self {
+        Self::point(offset)
+    }
+}
+
+impl Span<usize> {
+    /// Moves the span by `delta`, saturating the bounds at `0`,
+    /// e.g. to relocate a span relative to a sub-slice into its parent source.
+    ///
+    /// The implicit `0` start of a `RangeTo` is moved too, so it becomes a `Range` unless
+    /// `delta` is not positive. `None` and `RangeFull` are returned unchanged.
+    #[inline]
+    pub fn shift(&self, delta: isize) -> Self {
+        let shift = |offset: usize| offset.saturating_add_signed(delta);
+
+        match self {
+            Span::None => Span::None,
+            Span::Range(range) => Span::Range(shift(range.start)..shift(range.end)),
+            Span::RangeFrom(range) => Span::RangeFrom(shift(range.start)..),
+            Span::RangeTo(range) if delta <= 0 => Span::RangeTo(..shift(range.end)),
+            Span::RangeTo(range) => Span::Range(shift(0)..shift(range.end)),
+            Span::RangeFull => Span::RangeFull,
+        }
+    }
+}
+
 impl<Idx> From<RangeFrom<Idx>> for Span<Idx> {
     fn from(value: RangeFrom<Idx>) -> Self {
         Self::RangeFrom(value)
@@ -46,6 +82,17 @@ impl<Idx> From<RangeFull> for Span<Idx> {
     }
 }
 
+impl<Idx> Span<Idx>
+where
+    Idx: Copy,
+{
+    /// Creates a zero-length span at `offset`.
+    #[inline]
+    pub fn point(offset: Idx) -> Self {
+        Span::Range(offset..offset)
+    }
+}
+
 impl<Idx> Span<Idx>
 where
     Idx: Ord + Copy,
@@ -129,6 +176,12 @@ where
     }
 
     /// Union two range.
+    ///
+    /// For two [`Span::Range`]s, returns the smallest range covering both, including the gap
+    /// between disjoint ranges. [`Span::None`] is the identity:
+    /// `Span::None.union(&x) == x` and `x.union(&Span::None) == x`.
+    ///
+    /// The `+` operator is the same operation, `a + b == a.union(&b)`.
     #[inline]
     pub fn union(&self, other: &Self) -> Self {
         match (self, other) {
@@ -177,7 +230,7 @@ where
                     return Span::None;
                 }
 
-                return Span::RangeFull;
+                Span::RangeFull
             }
             (Span::RangeTo(range_to), Span::RangeTo(other_range_to)) => {
                 let end = cmp::max(range_to.end, other_range_to.end);
@@ -197,12 +250,14 @@ where
     }
 }
 
+/// Same as [`Span::union`].
 impl<Idx> ops::Add for Span<Idx>
 where
     Idx: Ord + Copy,
 {
     type Output = Span<Idx>;
 
+    #[inline]
     fn add(self, rhs: Self) -> Self::Output {
         self.union(&rhs)
     }
@@ -212,6 +267,30 @@ where
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_from() {
+        assert_eq!(Span::from(1..3), Span::Range(1..3));
+        assert_eq!(Span::from((1, 3)), Span::Range(1..3));
+        assert_eq!(Span::from(4usize), Span::Range(4..4));
+        assert_eq!(Span::point(4), Span::Range(4..4));
+        assert_eq!(Span::<usize>::from(..), Span::RangeFull);
+    }
+
+    #[test]
+    fn test_shift() {
+        assert_eq!(Span::Range(2..5).shift(10), Span::Range(12..15));
+        assert_eq!(Span::Range(12..15).shift(-10), Span::Range(2..5));
+        assert_eq!(Span::Range(2..5).shift(-3), Span::Range(0..2));
+        assert_eq!(Span::Range(2..5).shift(-8), Span::Range(0..0));
+
+        assert_eq!(Span::RangeFrom(2..).shift(3), Span::RangeFrom(5..));
+        assert_eq!(Span::RangeTo(..4).shift(3), Span::Range(3..7));
+        assert_eq!(Span::RangeTo(..4).shift(-6), Span::RangeTo(..0));
+
+        assert_eq!(Span::None.shift(3), Span::None);
+        assert_eq!(Span::RangeFull.shift(3), Span::RangeFull);
+    }
+
     #[test]
     fn test_between() {
         assert_eq!(
@@ -294,4 +373,24 @@ mod tests {
             Span::RangeTo(..18)
         );
     }
+
+    #[test]
+    fn test_add() {
+        assert_eq!(Span::None + Span::Range(2..4), Span::Range(2..4));
+        assert_eq!(Span::Range(2..4) + Span::None, Span::Range(2..4));
+        assert_eq!(Span::<usize>::None + Span::None, Span::None);
+
+        assert_eq!(Span::Range(1..3) + Span::Range(8..10), Span::Range(1..10));
+        assert_eq!(Span::Range(8..10) + Span::Range(1..3), Span::Range(1..10));
+
+        for (lhs, rhs) in [
+            (Span::None, Span::Range(2..4)),
+            (Span::Range(2..4), Span::None),
+            (Span::Range(1..3), Span::Range(8..10)),
+            (Span::Range(1..3), Span::RangeFrom(8..)),
+            (Span::RangeTo(..14), Span::RangeFrom(18..)),
+        ] {
+            assert_eq!(lhs.clone() + rhs.clone(), lhs.union(&rhs));
+        }
+    }
 }
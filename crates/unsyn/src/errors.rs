@@ -1,6 +1,6 @@
 //! The types used for `unsyn` parsing error reports.
 
-use parserc::{ControlFlow, ParseError, Span};
+use parserc::{ControlFlow, Deescalate, IntoErrorKind, ParseError, Span};
 
 /// Error for punct tokens.
 #[derive(Debug, thiserror::Error, PartialEq, Eq)]
@@ -15,7 +15,7 @@ pub enum PunctKind {
     Star,
     #[error("punct '?'")]
     Question,
-    #[error("punct '～'")]
+    #[error("punct '~'")]
     Tilde,
     #[error("punct '|'")]
     Or,
@@ -43,15 +43,24 @@ pub enum PunctKind {
     ArrowRight,
     #[error("punct '..'")]
     DotDot,
+    #[error("punct '..='")]
+    DotDotEq,
     #[error("punct '-'")]
     Minus,
 }
 
+impl IntoErrorKind<UnsynError> for PunctKind {
+    #[inline]
+    fn into_error(self, control_flow: ControlFlow, span: Span) -> UnsynError {
+        UnsynError::Punct(self, control_flow, span)
+    }
+}
+
 impl PunctKind {
     /// Map error to `punct` error.
     #[inline]
     pub fn map(self) -> impl FnOnce(UnsynError) -> UnsynError {
-        |err: UnsynError| UnsynError::Punct(self, err.control_flow(), err.to_span())
+        |err: UnsynError| self.into_error(err.control_flow(), err.to_span())
     }
 }
 
@@ -84,11 +93,18 @@ pub enum KeywordKind {
     Concat,
 }
 
+impl IntoErrorKind<UnsynError> for KeywordKind {
+    #[inline]
+    fn into_error(self, control_flow: ControlFlow, span: Span) -> UnsynError {
+        UnsynError::Keyword(self, control_flow, span)
+    }
+}
+
 impl KeywordKind {
     /// Map error to `punct` error.
     #[inline]
     pub fn map(self) -> impl FnOnce(UnsynError) -> UnsynError {
-        |err: UnsynError| UnsynError::Keyword(self, err.control_flow(), err.to_span())
+        |err: UnsynError| self.into_error(err.control_flow(), err.to_span())
     }
 }
 
@@ -123,15 +139,32 @@ pub enum SyntaxKind {
     UnicodeEscape,
     #[error("literal decimal number")]
     Dec,
+    #[error("literal integer")]
+    Int,
+    #[error("literal float")]
+    Float,
+    #[error("literal raw string")]
+    RawStr,
+    #[error("literal char")]
+    Char,
+    #[error("lifetime")]
+    Lifetime,
     #[error("ExprNoTopAlt")]
     ExprNoTopAlt,
 }
 
+impl IntoErrorKind<UnsynError> for SyntaxKind {
+    #[inline]
+    fn into_error(self, control_flow: ControlFlow, span: Span) -> UnsynError {
+        UnsynError::Syntax(self, control_flow, span)
+    }
+}
+
 impl SyntaxKind {
     /// Map error to `syntax` error.
     #[inline]
     pub fn map(self) -> impl FnOnce(UnsynError) -> UnsynError {
-        |err: UnsynError| UnsynError::Syntax(self, err.control_flow(), err.to_span())
+        |err: UnsynError| self.into_error(err.control_flow(), err.to_span())
     }
 
     /// Map error to `syntax` fatal error.
@@ -162,6 +195,16 @@ pub enum SemanticsKind {
     EmptySet,
     #[error("invalid set item")]
     SetItem,
+    #[error("integer literal without digits")]
+    IntDigits,
+    #[error("malformed float literal")]
+    Float,
+    #[error("unterminated raw string")]
+    RawStr,
+    #[error("empty char literal")]
+    CharEmpty,
+    #[error("char literal must be one char")]
+    CharTooLong,
 }
 
 impl SemanticsKind {
@@ -238,3 +281,25 @@ impl ParseError for UnsynError {
         }
     }
 }
+
+impl Deescalate for UnsynError {
+    /// Semantics errors are always fatal and are returned unchanged.
+    #[inline]
+    fn deescalate(self) -> Self {
+        match self {
+            UnsynError::Kind(kind) => Self::Kind(kind.deescalate()),
+            UnsynError::Syntax(syntax_kind, control_flow, span) => {
+                Self::Syntax(syntax_kind, control_flow.deescalate(), span)
+            }
+            UnsynError::Punct(punct_kind, control_flow, span) => {
+                UnsynError::Punct(punct_kind, control_flow.deescalate(), span)
+            }
+            UnsynError::Keyword(keyword_kind, control_flow, span) => {
+                UnsynError::Keyword(keyword_kind, control_flow.deescalate(), span)
+            }
+            UnsynError::Semantics(semantics_kind, span) => {
+                UnsynError::Semantics(semantics_kind, span)
+            }
+        }
+    }
+}
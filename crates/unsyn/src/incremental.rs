@@ -0,0 +1,421 @@
+//! Incremental reparsing of `unsyn` syntax trees after a text edit.
+//!
+//! Every token keeps its raw input slice, so an item the edit doesn't touch has the same text
+//! in the edited source, shifted by the length change of the edit. [`reparse`] moves those items
+//! onto the edited source with [`Rebase`] and only parses the items around the edit.
+
+use parserc::{
+    AsStr, Input, Span,
+    syntax::{Delimiter, Punctuated, SeparatedNonEmpty, Syntax, SyntaxInput},
+};
+
+use crate::{
+    errors::UnsynError,
+    input::TokenStream,
+    lexical::{
+        S,
+        comments::{
+            BlockComment, InnerBlockDoc, InnerLineDoc, LineComment, OuterBlockDoc, OuterLineDoc,
+        },
+        ident::{Ident, Lifetime},
+        lit::{
+            ASCIIEscape, CharContent, LitChar, LitDec, LitFloat, LitInt, LitRawStr, LitStr,
+            LitUnicode, QuoteEscape, StrSegment, UnicodeEscape,
+        },
+    },
+    syntax::{
+        Crate, Expr, ExprNoTopAlt, ExprNoTopAlts, ExprWithSuffix, ExprWithoutSuffix, InnerDoc,
+        Item, ModuleDeclaration, OuterDoc, Path, PathSegment, Range, Repeat, SetItem, Stmt,
+        UseDeclaration, UseTree,
+    },
+};
+
+/// A text edit: the bytes in `range` of the old source are replaced with `text`.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Edit<'a> {
+    /// The replaced byte range of the old source.
+    pub range: std::ops::Range<usize>,
+    /// The inserted text.
+    pub text: &'a str,
+}
+
+impl Edit<'_> {
+    /// Returns how far the text after the edit moves, in bytes.
+    #[inline]
+    pub fn delta(&self) -> isize {
+        self.text.len() as isize - self.range.len() as isize
+    }
+}
+
+/// A syntax tree node that can be moved onto another source containing the same text.
+pub trait Rebase<'a> {
+    /// The node type over the new source.
+    type Output;
+
+    /// Returns this node over `source`, with every input slice moved by `delta` bytes,
+    /// or `None` if a moved slice is not in `source` or its text differs.
+    fn rebase(&self, source: &'a str, delta: isize) -> Option<Self::Output>;
+}
+
+impl<'a> Rebase<'a> for TokenStream<'_> {
+    type Output = TokenStream<'a>;
+
+    #[inline]
+    fn rebase(&self, source: &'a str, delta: isize) -> Option<Self::Output> {
+        let start = self.start().checked_add_signed(delta)?;
+        let text = source.get(start..start + self.len())?;
+
+        (text == self.as_str()).then(|| TokenStream::from((start, text)))
+    }
+}
+
+impl<'a, T> Rebase<'a> for Option<T>
+where
+    T: Rebase<'a>,
+{
+    type Output = Option<T::Output>;
+
+    #[inline]
+    fn rebase(&self, source: &'a str, delta: isize) -> Option<Self::Output> {
+        match self {
+            Some(value) => value.rebase(source, delta).map(Some),
+            None => Some(None),
+        }
+    }
+}
+
+impl<'a, T> Rebase<'a> for Box<T>
+where
+    T: Rebase<'a>,
+{
+    type Output = Box<T::Output>;
+
+    #[inline]
+    fn rebase(&self, source: &'a str, delta: isize) -> Option<Self::Output> {
+        self.as_ref().rebase(source, delta).map(Box::new)
+    }
+}
+
+impl<'a, T> Rebase<'a> for Vec<T>
+where
+    T: Rebase<'a>,
+{
+    type Output = Vec<T::Output>;
+
+    #[inline]
+    fn rebase(&self, source: &'a str, delta: isize) -> Option<Self::Output> {
+        self.iter()
+            .map(|value| value.rebase(source, delta))
+            .collect()
+    }
+}
+
+impl<'a, T1, T2> Rebase<'a> for (T1, T2)
+where
+    T1: Rebase<'a>,
+    T2: Rebase<'a>,
+{
+    type Output = (T1::Output, T2::Output);
+
+    #[inline]
+    fn rebase(&self, source: &'a str, delta: isize) -> Option<Self::Output> {
+        Some((self.0.rebase(source, delta)?, self.1.rebase(source, delta)?))
+    }
+}
+
+impl<'a, Start, End, Body> Rebase<'a> for Delimiter<Start, End, Body>
+where
+    Start: Rebase<'a>,
+    End: Rebase<'a>,
+    Body: Rebase<'a>,
+{
+    type Output = Delimiter<Start::Output, End::Output, Body::Output>;
+
+    #[inline]
+    fn rebase(&self, source: &'a str, delta: isize) -> Option<Self::Output> {
+        Some(Delimiter {
+            start: self.start.rebase(source, delta)?,
+            end: self.end.rebase(source, delta)?,
+            body: self.body.rebase(source, delta)?,
+        })
+    }
+}
+
+impl<'a, T, P, const TRAILING: bool> Rebase<'a> for Punctuated<T, P, TRAILING>
+where
+    T: Rebase<'a>,
+    P: Rebase<'a>,
+{
+    type Output = Punctuated<T::Output, P::Output, TRAILING>;
+
+    #[inline]
+    fn rebase(&self, source: &'a str, delta: isize) -> Option<Self::Output> {
+        Some(Punctuated {
+            pairs: self.pairs.rebase(source, delta)?,
+            tail: self.tail.rebase(source, delta)?,
+        })
+    }
+}
+
+impl<'a, T, P> Rebase<'a> for SeparatedNonEmpty<T, P>
+where
+    T: Rebase<'a>,
+    P: Rebase<'a>,
+{
+    type Output = SeparatedNonEmpty<T::Output, P::Output>;
+
+    #[inline]
+    fn rebase(&self, source: &'a str, delta: isize) -> Option<Self::Output> {
+        Some(SeparatedNonEmpty {
+            first: self.first.rebase(source, delta)?,
+            rest: self.rest.rebase(source, delta)?,
+        })
+    }
+}
+
+/// Implements [`Rebase`] for a syntax node by rebasing its fields.
+macro_rules! impl_rebase {
+    (struct $name: ident { $($field: tt),* $(,)? }) => {
+        impl<'a> Rebase<'a> for $name<TokenStream<'_>> {
+            type Output = $name<TokenStream<'a>>;
+
+            #[inline]
+            fn rebase(&self, source: &'a str, delta: isize) -> Option<Self::Output> {
+                Some($name {
+                    $($field: self.$field.rebase(source, delta)?,)*
+                })
+            }
+        }
+    };
+    (enum $name: ident { $($variant: ident $(($($tuple: ident),*))? $({$($named: ident),*})?),* $(,)? }) => {
+        impl<'a> Rebase<'a> for $name<TokenStream<'_>> {
+            type Output = $name<TokenStream<'a>>;
+
+            #[inline]
+            fn rebase(&self, source: &'a str, delta: isize) -> Option<Self::Output> {
+                Some(match self {
+                    $(
+                        $name::$variant $(($($tuple),*))? $({$($named),*})? => {
+                            $name::$variant
+                                $(($($tuple.rebase(source, delta)?),*))?
+                                $({$($named: $named.rebase(source, delta)?),*})?
+                        }
+                    )*
+                })
+            }
+        }
+    };
+}
+
+for_each_node!(impl_rebase);
+
+/// Parses `new_source`, the source of `old` with `edit` applied, reusing the items of `old`
+/// the edit doesn't touch.
+///
+/// Items ending before the edit are moved as they are, items starting after it are moved by
+/// [`Edit::delta`]; one more item on each side of the edit is reparsed, since tokens can look
+/// ahead. Falls back to a full parse if the edit touches the inner documents, if the edit doesn't
+/// match `new_source`, or if the reparsed items don't end exactly where the reused items start,
+/// e.g. when the edit opens a block comment.
+///
+/// `old` must be parsed from the start of its source.
+pub fn reparse<'a>(
+    old: &Crate<TokenStream<'_>>,
+    edit: Edit<'_>,
+    new_source: &'a str,
+) -> Result<Crate<TokenStream<'a>>, UnsynError> {
+    let full = || TokenStream::from(new_source).parse::<Crate<_>>();
+
+    let inserted = edit.range.start..edit.range.start + edit.text.len();
+
+    if edit.range.start > edit.range.end || new_source.get(inserted) != Some(edit.text) {
+        return full();
+    }
+
+    let Some(mut bounds) = old
+        .items
+        .iter()
+        .map(|item| match item.to_span() {
+            Span::Range(range) => Some(range),
+            _ => None,
+        })
+        .collect::<Option<Vec<_>>>()
+    else {
+        return full();
+    };
+
+    // items follow each other, the span of an item may leave out its trailing trivia.
+    for index in 1..bounds.len() {
+        bounds[index - 1].end = bounds[index].start;
+    }
+
+    match bounds.first() {
+        Some(bound) if bound.start < edit.range.start => {}
+        _ => return full(),
+    }
+
+    // the first reparsed item, and the first reused item after the edit.
+    let first = bounds
+        .iter()
+        .position(|bound| bound.end >= edit.range.start)
+        .unwrap_or(bounds.len())
+        .saturating_sub(1);
+
+    let last = bounds
+        .iter()
+        .position(|bound| bound.start > edit.range.end)
+        .map_or(bounds.len(), |index| (index + 1).min(bounds.len()));
+
+    let delta = edit.delta();
+
+    let region_start = bounds[first].start;
+
+    let region_end = match bounds.get(last) {
+        Some(bound) => match bound.start.checked_add_signed(delta) {
+            Some(end) => end,
+            None => return full(),
+        },
+        None => new_source.len(),
+    };
+
+    let Some(region) = new_source.get(region_start..region_end) else {
+        return full();
+    };
+
+    let mut input = TokenStream::from((region_start, region));
+
+    let items = input.parse::<Vec<Item<_>>>()?;
+
+    if last < bounds.len() && !input.is_empty() {
+        return full();
+    }
+
+    let rebased = (|| {
+        let inner_docs = old.inner_docs.rebase(new_source, 0)?;
+        let prefix = old.items[..first]
+            .iter()
+            .map(|item| item.rebase(new_source, 0))
+            .collect::<Option<Vec<_>>>()?;
+        let suffix = old.items[last..]
+            .iter()
+            .map(|item| item.rebase(new_source, delta))
+            .collect::<Option<Vec<_>>>()?;
+
+        Some((inner_docs, prefix, suffix))
+    })();
+
+    let Some((inner_docs, prefix, suffix)) = rebased else {
+        return full();
+    };
+
+    Ok(Crate {
+        inner_docs,
+        items: prefix.into_iter().chain(items).chain(suffix).collect(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use std::ops::Range as TextRange;
+
+    use super::*;
+
+    const SOURCE: &str =
+        "use a;\nmod b;\nlexer A -> 'a';\nlexer B -> 'b';\nlexer C -> 'c';\nlexer D -> 'd';\n";
+
+    fn edit<'a>(source: &str, range: TextRange<usize>, text: &'a str) -> (Edit<'a>, String) {
+        let mut new_source = source.to_owned();
+        new_source.replace_range(range.clone(), text);
+
+        (Edit { range, text }, new_source)
+    }
+
+    #[test]
+    fn test_single_token_edit() {
+        let old = TokenStream::from(SOURCE).parse::<Crate<_>>().unwrap();
+
+        let at = SOURCE.find("'b'").unwrap();
+        let (edit, new_source) = edit(SOURCE, at..at + 3, "'bb'");
+
+        let new = reparse(&old, edit, &new_source).unwrap();
+
+        assert_eq!(
+            new,
+            TokenStream::from(new_source.as_str())
+                .parse::<Crate<_>>()
+                .unwrap()
+        );
+        assert_eq!(new.to_source(), new_source);
+
+        assert_eq!(old.items.len(), new.items.len());
+        assert_eq!(old.items[..2], new.items[..2]);
+
+        let (Some(Item::Stmt(old)), Some(Item::Stmt(new))) = (old.items.last(), new.items.last())
+        else {
+            panic!("expect stmt");
+        };
+
+        assert_eq!(new.to_span(), old.to_span().shift(1));
+        assert_eq!(new.to_source(), old.to_source());
+        assert_eq!(old.rebase(&new_source, 1).as_ref(), Some(new));
+        assert_eq!(old.rebase(&new_source, 0), None);
+    }
+
+    #[test]
+    fn test_edit_at_end() {
+        let old = TokenStream::from(SOURCE).parse::<Crate<_>>().unwrap();
+
+        let (edit, new_source) = edit(SOURCE, SOURCE.len()..SOURCE.len(), "mod d;");
+
+        assert_eq!(
+            reparse(&old, edit, &new_source).unwrap(),
+            TokenStream::from(new_source.as_str())
+                .parse::<Crate<_>>()
+                .unwrap()
+        );
+    }
+
+    #[test]
+    fn test_fallback() {
+        let source = "//! doc\nuse a;\nmod b;\nlexer A -> 'a';\n";
+        let old = TokenStream::from(source).parse::<Crate<_>>().unwrap();
+
+        for (range, text) in [(2..2, "!"), (15..15, "/* ")] {
+            let (edit, new_source) = edit(source, range, text);
+
+            assert_eq!(
+                reparse(&old, edit, &new_source),
+                TokenStream::from(new_source.as_str()).parse::<Crate<_>>()
+            );
+        }
+    }
+
+    #[test]
+    #[allow(clippy::reversed_empty_ranges)]
+    fn test_mismatched_edit() {
+        let old = TokenStream::from(SOURCE).parse::<Crate<_>>().unwrap();
+
+        // the edit claims a different range than the one changed in the new source.
+        let new_source = SOURCE.replacen("'d'", "'dd'", 1);
+
+        for edit in [
+            Edit {
+                range: 7..8,
+                text: "x",
+            },
+            Edit {
+                range: 8..8,
+                text: "",
+            },
+            Edit {
+                range: 8..4,
+                text: "",
+            },
+        ] {
+            assert_eq!(
+                reparse(&old, edit, &new_source),
+                TokenStream::from(new_source.as_str()).parse::<Crate<_>>()
+            );
+        }
+    }
+}
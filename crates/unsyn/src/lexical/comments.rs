@@ -34,7 +34,7 @@ where
                     header.to_span(),
                 ));
             }
-            2 => match input.iter().next() {
+            2 => match input.peek() {
                 Some('!') | Some('\n') => {
                     return Err(UnsynError::Syntax(
                         SyntaxKind::LineComment,
@@ -109,7 +109,7 @@ where
 
         keyword("///").parse(input)?;
 
-        if let Some('/') = input.iter().next() {
+        if let Some('/') = input.peek() {
             return Err(UnsynError::Syntax(
                 SyntaxKind::OuterLineDoc,
                 ControlFlow::Recovable,
@@ -193,10 +193,10 @@ where
                 ));
             }
 
-            if let Some(_) = keyword("*/").ok().parse(input)? {
+            if keyword("*/").ok().parse(input)?.is_some() {
                 offset += 2;
                 break;
-            } else if let Some(_) = next('*').ok().parse(input)? {
+            } else if next('*').ok().parse(input)?.is_some() {
                 offset += 1;
             }
         }
@@ -251,10 +251,10 @@ where
                 ));
             }
 
-            if let Some(_) = keyword("*/").ok().parse(input)? {
+            if keyword("*/").ok().parse(input)?.is_some() {
                 offset += 2;
                 break;
-            } else if let Some(_) = next('*').ok().parse(input)? {
+            } else if next('*').ok().parse(input)?.is_some() {
                 offset += 1;
             }
         }
@@ -289,7 +289,7 @@ where
             .parse(input)
             .map_err(SyntaxKind::OuterBlockDoc.map())?;
 
-        if let Some('*') = input.iter().next() {
+        if let Some('*') = input.peek() {
             return Err(UnsynError::Syntax(
                 SyntaxKind::OuterBlockDoc,
                 ControlFlow::Recovable,
@@ -317,10 +317,10 @@ where
                 ));
             }
 
-            if let Some(_) = keyword("*/").ok().parse(input)? {
+            if keyword("*/").ok().parse(input)?.is_some() {
                 offset += 2;
                 break;
-            } else if let Some(_) = next('*').ok().parse(input)? {
+            } else if next('*').ok().parse(input)?.is_some() {
                 offset += 1;
             }
         }
@@ -1,20 +1,45 @@
 //! ident token of `unsyn` language.
 
-use parserc::{Parser, next_if, syntax::Syntax, take_while};
-use unicode_ident::{is_xid_continue, is_xid_start};
+use parserc::{
+    ControlFlow, Parser, keyword, next, next_if,
+    predicates::{ident_continue, ident_start},
+    syntax::Syntax,
+    take_while,
+};
 
 use crate::{
     errors::{SemanticsKind, SyntaxKind, UnsynError},
     input::UnsynInput,
+    lexical::keyword::KEYWORDS,
 };
 
 /// A identifier except a keyword.
+///
+/// A raw identifier, e.g. `r#use`, may be a keyword, the span covers the `r#` prefix.
 #[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Clone)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Ident<I>(pub I)
 where
     I: UnsynInput;
 
+impl<I> Ident<I>
+where
+    I: UnsynInput,
+{
+    /// Returns true if this is a raw identifier, e.g. `r#use`.
+    #[inline]
+    pub fn is_raw(&self) -> bool {
+        self.0.as_str().starts_with("r#")
+    }
+
+    /// Returns the identifier name without the raw prefix `r#`.
+    #[inline]
+    pub fn name(&self) -> &str {
+        let name = self.0.as_str();
+        name.strip_prefix("r#").unwrap_or(name)
+    }
+}
+
 impl<I> Syntax<I> for Ident<I>
 where
     I: UnsynInput,
@@ -23,22 +48,32 @@ where
     fn parse(input: &mut I) -> Result<Self, <I as parserc::Input>::Error> {
         let mut content = input.clone();
 
-        _ = next_if(|c| c == '_' || is_xid_start(c))
+        let raw = (|input: &mut I| {
+            keyword("r#").parse(input)?;
+            next_if(ident_start).parse(input)
+        })
+        .ok()
+        .parse(input)?;
+
+        if raw.is_some() {
+            let rest = take_while(ident_continue).parse(input)?;
+
+            return Ok(Self(content.split_to(3 + rest.len())));
+        }
+
+        _ = next_if(ident_start)
             .parse(input)
             .map_err(SyntaxKind::Ident.map())?;
 
-        let rest = take_while(|c| is_xid_continue(c)).parse(input)?;
+        let rest = take_while(ident_continue).parse(input)?;
 
         let content = content.split_to(1 + rest.len());
 
-        match content.as_str() {
-            "lexer" | "syntax" | "followed" | "except" | "use" | "super" | "crate" | "concat" => {
-                return Err(UnsynError::Semantics(
-                    SemanticsKind::Keyword,
-                    content.to_span(),
-                ));
-            }
-            _ => {}
+        if KEYWORDS.contains(&content.as_str()) {
+            return Err(UnsynError::Semantics(
+                SemanticsKind::Keyword,
+                content.to_span(),
+            ));
         }
 
         Ok(Self(content))
@@ -49,3 +84,127 @@ where
         self.0.to_span()
     }
 }
+
+/// A lifetime or label: a single quote followed by an identifier, e.g. `'a` or `'static`.
+///
+/// A lifetime has no closing quote, so `'x'` is rejected with a recoverable error and left
+/// to be parsed as a [`LitChar`](crate::lexical::lit::LitChar).
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Lifetime<I>(pub I)
+where
+    I: UnsynInput;
+
+impl<I> Syntax<I> for Lifetime<I>
+where
+    I: UnsynInput,
+{
+    #[inline]
+    fn parse(input: &mut I) -> Result<Self, <I as parserc::Input>::Error> {
+        let mut content = input.clone();
+
+        next('\'')
+            .parse(input)
+            .map_err(SyntaxKind::Lifetime.map())?;
+
+        next_if(ident_start)
+            .parse(input)
+            .map_err(SyntaxKind::Lifetime.map())?;
+
+        let rest = take_while(ident_continue).parse(input)?;
+
+        if let Some('\'') = input.peek() {
+            return Err(UnsynError::Syntax(
+                SyntaxKind::Lifetime,
+                ControlFlow::Recovable,
+                content.to_span_at(rest.len() + 3),
+            ));
+        }
+
+        Ok(Self(content.split_to(rest.len() + 2)))
+    }
+
+    #[inline]
+    fn to_span(&self) -> parserc::Span {
+        self.0.to_span()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use parserc::{
+        Span,
+        syntax::{Or, SyntaxInput},
+    };
+
+    use crate::{
+        input::TokenStream,
+        lexical::lit::{CharContent, LitChar},
+    };
+
+    use super::*;
+
+    #[test]
+    fn test_lifetime() {
+        assert_eq!(
+            TokenStream::from("'a").parse::<Lifetime<_>>(),
+            Ok(Lifetime(TokenStream::from((0, "'a"))))
+        );
+
+        assert_eq!(
+            TokenStream::from("'static>").parse::<Lifetime<_>>(),
+            Ok(Lifetime(TokenStream::from((0, "'static"))))
+        );
+
+        assert_eq!(
+            TokenStream::from("'x'").parse::<Lifetime<_>>(),
+            Err(UnsynError::Syntax(
+                SyntaxKind::Lifetime,
+                ControlFlow::Recovable,
+                Span::Range(0..3)
+            ))
+        );
+    }
+
+    #[test]
+    fn test_lifetime_or_char() {
+        assert_eq!(
+            TokenStream::from("'x'").parse::<Or<Lifetime<_>, LitChar<_>>>(),
+            Ok(Or::Second(LitChar {
+                delimiter_start: TokenStream::from((0, "'")),
+                content: CharContent::Char(TokenStream::from((1, "x"))),
+                delimiter_end: TokenStream::from((2, "'"))
+            }))
+        );
+
+        assert_eq!(
+            TokenStream::from("'static").parse::<Or<LitChar<_>, Lifetime<_>>>(),
+            Ok(Or::Second(Lifetime(TokenStream::from((0, "'static")))))
+        );
+    }
+
+    #[test]
+    fn test_raw_ident() {
+        let ident = TokenStream::from("r#use").parse::<Ident<_>>().unwrap();
+        assert_eq!(ident, Ident(TokenStream::from((0, "r#use"))));
+        assert!(ident.is_raw());
+        assert_eq!(ident.name(), "use");
+        assert_eq!(ident.to_span(), Span::Range(0..5));
+
+        let ident = TokenStream::from("r#abc").parse::<Ident<_>>().unwrap();
+        assert!(ident.is_raw());
+        assert_eq!(ident.name(), "abc");
+
+        let ident = TokenStream::from("r").parse::<Ident<_>>().unwrap();
+        assert!(!ident.is_raw());
+        assert_eq!(ident.name(), "r");
+
+        assert_eq!(
+            TokenStream::from("use").parse::<Ident<_>>(),
+            Err(UnsynError::Semantics(
+                SemanticsKind::Keyword,
+                Span::Range(0..3)
+            ))
+        );
+    }
+}
@@ -24,7 +24,7 @@ macro_rules! define_keyword {
                     .parse(input)
                     .map_err(crate::errors::KeywordKind::$ident.map())?;
 
-                if let Some(c) = input.iter().next() {
+                if let Some(c) = input.peek() {
                     if c.is_alphanumeric() || c == '_' {
                         return Err(crate::errors::UnsynError::Keyword(
                             crate::errors::KeywordKind::$ident,
@@ -42,18 +42,97 @@ macro_rules! define_keyword {
                 self.0.to_span() + self.1.to_span()
             }
         }
+
+        impl<I> parserc::syntax::ToSource for $ident<I>
+        where
+            I: crate::input::UnsynInput + parserc::syntax::ToSource,
+        {
+            #[inline]
+            fn to_source(&self, out: &mut String) {
+                self.0.to_source(out);
+                self.1.to_source(out);
+            }
+        }
+
+        impl<'a> crate::incremental::Rebase<'a> for $ident<crate::input::TokenStream<'_>> {
+            type Output = $ident<crate::input::TokenStream<'a>>;
+
+            #[inline]
+            fn rebase(&self, source: &'a str, delta: isize) -> Option<Self::Output> {
+                Some($ident(
+                    self.0.rebase(source, delta)?,
+                    self.1.rebase(source, delta)?,
+                ))
+            }
+        }
     };
 }
 
-define_keyword!(Whitespace, "whitespace");
-define_keyword!(Lexer, "lexer");
-define_keyword!(Syntax, "syntax");
-define_keyword!(Followed, "followed");
-define_keyword!(Concat, "concat");
-define_keyword!(Except, "except");
-define_keyword!(Use, "use");
-define_keyword!(Super, "super");
-define_keyword!(Crate, "crate");
-define_keyword!(As, "as");
-define_keyword!(This, "this");
-define_keyword!(Mod, "mod");
+macro_rules! define_keywords {
+    ($($ident: ident => $value: literal),* $(,)?) => {
+        $(define_keyword!($ident, $value);)*
+
+        /// All keywords of `unsyn` language, which can't be used as a bare [`Ident`](super::ident::Ident).
+        pub const KEYWORDS: &[&str] = &[$($value),*];
+    };
+}
+
+define_keywords! {
+    Whitespace => "whitespace",
+    Lexer => "lexer",
+    Syntax => "syntax",
+    Followed => "followed",
+    Concat => "concat",
+    Except => "except",
+    Use => "use",
+    Super => "super",
+    Crate => "crate",
+    As => "as",
+    This => "this",
+    Mod => "mod",
+}
+
+#[cfg(test)]
+mod tests {
+    use parserc::syntax::SyntaxInput;
+
+    use crate::{
+        errors::{SemanticsKind, UnsynError},
+        input::TokenStream,
+        lexical::ident::Ident,
+    };
+
+    use super::*;
+
+    #[test]
+    fn test_keywords() {
+        macro_rules! assert_keyword {
+            ($($ident: ident),*) => {
+                $(assert!(TokenStream::from(stringify!($ident).to_lowercase().as_str())
+                    .parse::<$ident<_>>()
+                    .is_ok());)*
+
+                assert_eq!(KEYWORDS.len(), [$(stringify!($ident)),*].len());
+            };
+        }
+
+        assert_keyword!(
+            Whitespace, Lexer, Syntax, Followed, Concat, Except, Use, Super, Crate, As, This, Mod
+        );
+    }
+
+    #[test]
+    fn test_keyword_ident() {
+        for keyword in KEYWORDS {
+            assert_eq!(
+                TokenStream::from(*keyword).parse::<Ident<_>>(),
+                Err(UnsynError::Semantics(
+                    SemanticsKind::Keyword,
+                    parserc::Span::Range(0..keyword.len())
+                )),
+                "keyword: {}",
+                keyword
+            );
+        }
+    }
+}
@@ -1,9 +1,14 @@
 //! literal tokens.
 
 use parserc::{
-    ControlFlow, ParseError, Parser, keyword, next_if, syntax::Syntax, take_while, take_while_range,
+    ControlFlow, ParseError, Parser, keyword, next, next_if,
+    predicates::{ident_continue, ident_start},
+    syntax::Syntax,
+    take_while, take_while_range,
 };
 
+use unicode_ident::is_xid_start;
+
 use crate::{
     errors::{PunctKind, SemanticsKind, SyntaxKind, UnsynError},
     input::UnsynInput,
@@ -193,6 +198,91 @@ where
     pub delimiter_end: I,
 }
 
+/// Content of [`LitChar`]
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Clone, Syntax)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum CharContent<I>
+where
+    I: UnsynInput,
+{
+    QuoteEscape(QuoteEscape<I>),
+    ASCIIEscape(ASCIIEscape<I>),
+    UnicodeEscape(UnicodeEscape<I>),
+    Char(#[parserc(parser = next_if(|c: char| !matches!(c, '\'' | '\\' | '\n' | '\r' | '\t')))] I),
+}
+
+/// A character literal is a single Unicode character enclosed within two U+0027 (single-quote) characters,
+/// with the exception of U+0027 itself, which must be escaped by a preceding U+005C character (\).
+/// see [`The Rust Reference`]
+///
+/// A quote followed by an identifier without a closing quote, e.g. `'static`, is not a char literal,
+/// and returns a recoverable error.
+///
+/// [`The Rust Reference`]: https://doc.rust-lang.org/reference/tokens.html#character-literals
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct LitChar<I>
+where
+    I: UnsynInput,
+{
+    /// leading punct `'`
+    pub delimiter_start: I,
+    /// exactly one logical char.
+    pub content: CharContent<I>,
+    /// tailing punct `'`
+    pub delimiter_end: I,
+}
+
+impl<I> Syntax<I> for LitChar<I>
+where
+    I: UnsynInput,
+{
+    fn parse(input: &mut I) -> Result<Self, <I as parserc::Input>::Error> {
+        let delimiter_start = next('\'')
+            .parse(input)
+            .map_err(PunctKind::SingleQuote.map())?;
+
+        if let Some(delimiter_end) = next('\'').ok().parse(input)? {
+            return Err(UnsynError::Semantics(
+                SemanticsKind::CharEmpty,
+                delimiter_start.to_span() + delimiter_end.to_span(),
+            ));
+        }
+
+        let content = CharContent::into_parser()
+            .parse(input)
+            .map_err(SyntaxKind::Char.map())?;
+
+        if let Some(delimiter_end) = next('\'').ok().parse(input)? {
+            return Ok(Self {
+                delimiter_start,
+                content,
+                delimiter_end,
+            });
+        }
+
+        let rest = take_while(|c: char| c != '\'' && !c.is_whitespace()).parse(input)?;
+
+        if let Some(delimiter_end) = next('\'').ok().parse(input)? {
+            return Err(UnsynError::Semantics(
+                SemanticsKind::CharTooLong,
+                delimiter_start.to_span() + delimiter_end.to_span(),
+            ));
+        }
+
+        Err(UnsynError::Syntax(
+            SyntaxKind::Char,
+            ControlFlow::Recovable,
+            delimiter_start.to_span() + content.to_span() + rest.to_span(),
+        ))
+    }
+
+    #[inline]
+    fn to_span(&self) -> parserc::Span {
+        self.delimiter_start.to_span() + self.delimiter_end.to_span()
+    }
+}
+
 #[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Clone)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct LitUnicode<I>(pub I)
@@ -217,13 +307,13 @@ where
                 UnsynError::Semantics(SemanticsKind::Unicode, prefix.to_span() + err.to_span())
             })?;
 
-        if let Some(c) = input.iter().next() {
-            if c.is_ascii_hexdigit() {
-                return Err(UnsynError::Semantics(
-                    SemanticsKind::Unicode,
-                    prefix.to_span() + input.to_span_at(1),
-                ));
-            }
+        if let Some(c) = input.peek()
+            && c.is_ascii_hexdigit()
+        {
+            return Err(UnsynError::Semantics(
+                SemanticsKind::Unicode,
+                prefix.to_span() + input.to_span_at(1),
+            ));
         }
 
         Ok(Self(content.split_to(6)))
@@ -267,6 +357,273 @@ where
     }
 }
 
+/// An integer literal with an optional leading `-` and an optional radix prefix `0x`/`0o`/`0b`.
+///
+/// Digits may be mixed with underscores, e.g. `-1_000` or `0xFF_FF`.
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct LitInt<I>
+where
+    I: UnsynInput,
+{
+    /// optional leading `-`.
+    pub sign: Option<I>,
+    /// optional radix prefix: `0x`, `0o` or `0b`.
+    pub prefix: Option<I>,
+    /// digits and underscores.
+    pub digits: I,
+}
+
+impl<I> LitInt<I>
+where
+    I: UnsynInput,
+{
+    /// Returns the radix of this literal.
+    pub fn radix(&self) -> u32 {
+        match self.prefix.as_ref().map(|prefix| prefix.as_str()) {
+            Some("0x") => 16,
+            Some("0o") => 8,
+            Some("0b") => 2,
+            _ => 10,
+        }
+    }
+
+    /// Returns the value of this literal, or `None` if it overflows `i128`.
+    pub fn value(&self) -> Option<i128> {
+        let mut digits = self.digits.as_str().replace('_', "");
+
+        if self.sign.is_some() {
+            digits.insert(0, '-');
+        }
+
+        i128::from_str_radix(&digits, self.radix()).ok()
+    }
+}
+
+impl<I> Syntax<I> for LitInt<I>
+where
+    I: UnsynInput,
+{
+    fn parse(input: &mut I) -> Result<Self, <I as parserc::Input>::Error> {
+        let sign = next('-').ok().parse(input)?;
+
+        let prefix = keyword("0x")
+            .or(keyword("0o"))
+            .or(keyword("0b"))
+            .ok()
+            .parse(input)?;
+
+        let Some(prefix) = prefix else {
+            next_if(|c: char| c.is_ascii_digit())
+                .parse(&mut input.clone())
+                .map_err(SyntaxKind::Int.map())?;
+
+            let digits = take_while(|c: char| c.is_ascii_digit() || c == '_').parse(input)?;
+
+            return Ok(Self {
+                sign,
+                prefix: None,
+                digits,
+            });
+        };
+
+        let radix = match prefix.as_str() {
+            "0x" => 16,
+            "0o" => 8,
+            _ => 2,
+        };
+
+        let digits = take_while(|c: char| c.is_digit(radix) || c == '_').parse(input)?;
+
+        if !digits.as_str().chars().any(|c| c != '_') {
+            return Err(UnsynError::Semantics(
+                SemanticsKind::IntDigits,
+                prefix.to_span() + digits.to_span(),
+            ));
+        }
+
+        Ok(Self {
+            sign,
+            prefix: Some(prefix),
+            digits,
+        })
+    }
+
+    fn to_span(&self) -> parserc::Span {
+        let start = self
+            .sign
+            .as_ref()
+            .or(self.prefix.as_ref())
+            .unwrap_or(&self.digits);
+
+        start.to_span() + self.digits.to_span()
+    }
+}
+
+/// A floating-point literal, see [`The Rust Reference`]
+///
+/// A decimal literal followed by a `.` is not a float if another `.` comes next,
+/// so `1..2` is lexed as two decimal literals and a `..` punct.
+///
+/// [`The Rust Reference`]: https://doc.rust-lang.org/reference/tokens.html#floating-point-literals
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct LitFloat<I>(pub I)
+where
+    I: UnsynInput;
+
+impl<I> Syntax<I> for LitFloat<I>
+where
+    I: UnsynInput,
+{
+    fn parse(input: &mut I) -> Result<Self, <I as parserc::Input>::Error> {
+        let mut content = input.clone();
+
+        let integer = LitDec::into_parser()
+            .parse(input)
+            .map_err(SyntaxKind::Float.map())?;
+
+        let mut offset = integer.0.len();
+
+        let mut iter = input.iter();
+
+        let fraction = match (iter.next(), iter.next()) {
+            (Some('.'), Some(c)) if c == '.' || ident_start(c) => {
+                return Err(UnsynError::Syntax(
+                    SyntaxKind::Float,
+                    ControlFlow::Recovable,
+                    content.to_span_at(offset + 1),
+                ));
+            }
+            (Some('.'), _) => {
+                input.split_to(1);
+                offset += 1;
+
+                let fraction = take_while(|c: char| c.is_ascii_digit() || c == '_').parse(input)?;
+                offset += fraction.len();
+
+                Some(fraction)
+            }
+            _ => None,
+        };
+
+        let exponent = next_if(|c: char| c == 'e' || c == 'E').ok().parse(input)?;
+
+        if let Some(exponent) = &exponent {
+            offset += 1;
+
+            if let Some(sign) = next_if(|c: char| c == '+' || c == '-').ok().parse(input)? {
+                offset += sign.len();
+            }
+
+            let digits = take_while(|c: char| c.is_ascii_digit() || c == '_').parse(input)?;
+
+            offset += digits.len();
+
+            if !digits.as_str().chars().any(|c| c.is_ascii_digit()) {
+                return Err(UnsynError::Semantics(
+                    SemanticsKind::Float,
+                    exponent.to_span() + digits.to_span(),
+                ));
+            }
+        } else if fraction.is_none() {
+            return Err(UnsynError::Syntax(
+                SyntaxKind::Float,
+                ControlFlow::Recovable,
+                content.to_span_at(offset),
+            ));
+        }
+
+        // a float literal like `1.` can't have a suffix.
+        if (exponent.is_some() || fraction.is_some_and(|fraction| !fraction.is_empty()))
+            && let Some(c) = input.peek()
+            && is_xid_start(c)
+        {
+            offset += take_while(ident_continue).parse(input)?.len();
+        }
+
+        Ok(Self(content.split_to(offset)))
+    }
+
+    #[inline]
+    fn to_span(&self) -> parserc::Span {
+        self.0.to_span()
+    }
+}
+
+/// A raw string literal: `r"..."`, `r#"..."#`, `r##"..."##`, ...
+///
+/// The content is taken verbatim, without escape processing, and ends at the first `"` followed by
+/// as many `#`s as the opening delimiter.
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct LitRawStr<I>
+where
+    I: UnsynInput,
+{
+    /// leading chars `r#..#"`
+    pub delimiter_start: I,
+    /// verbatim content.
+    pub content: I,
+    /// tailing chars `"#..#`
+    pub delimiter_end: I,
+}
+
+impl<I> Syntax<I> for LitRawStr<I>
+where
+    I: UnsynInput,
+{
+    fn parse(input: &mut I) -> Result<Self, <I as parserc::Input>::Error> {
+        let mut content = input.clone();
+
+        keyword("r")
+            .parse(input)
+            .map_err(SyntaxKind::RawStr.map())?;
+
+        let hashes = take_while(|c: char| c == '#').parse(input)?.len();
+
+        next('"').parse(input).map_err(SyntaxKind::RawStr.map())?;
+
+        let delimiter_start = content.split_to(hashes + 2);
+
+        let mut offset = 0;
+
+        loop {
+            let Some(quote) = input.as_str()[offset..].find('"') else {
+                return Err(UnsynError::Semantics(
+                    SemanticsKind::RawStr,
+                    delimiter_start.to_span() + input.to_span(),
+                ));
+            };
+
+            offset += quote + 1;
+
+            let closing = input.as_str()[offset..]
+                .chars()
+                .take_while(|c| *c == '#')
+                .count();
+
+            if closing >= hashes {
+                break;
+            }
+        }
+
+        let content = input.split_to(offset - 1);
+        let delimiter_end = input.split_to(hashes + 1);
+
+        Ok(Self {
+            delimiter_start,
+            content,
+            delimiter_end,
+        })
+    }
+
+    #[inline]
+    fn to_span(&self) -> parserc::Span {
+        self.delimiter_start.to_span() + self.delimiter_end.to_span()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use parserc::{Span, syntax::SyntaxInput};
@@ -274,6 +631,7 @@ mod tests {
     use crate::{
         errors::{SemanticsKind, UnsynError},
         input::TokenStream,
+        lexical::punct::DotDot,
     };
 
     use super::*;
@@ -317,4 +675,256 @@ mod tests {
 
         println!("{:?}", TokenStream::from(r#"'\\\''"#).parse::<LitStr<_>>());
     }
+
+    #[test]
+    fn test_int() {
+        let int = TokenStream::from("-42").parse::<LitInt<_>>().unwrap();
+        assert_eq!(
+            int,
+            LitInt {
+                sign: Some(TokenStream::from((0, "-"))),
+                prefix: None,
+                digits: TokenStream::from((1, "42"))
+            }
+        );
+        assert_eq!(int.radix(), 10);
+        assert_eq!(int.value(), Some(-42));
+
+        let int = TokenStream::from("0xFF").parse::<LitInt<_>>().unwrap();
+        assert_eq!(int.radix(), 16);
+        assert_eq!(int.value(), Some(255));
+
+        let int = TokenStream::from("0o17").parse::<LitInt<_>>().unwrap();
+        assert_eq!(int.radix(), 8);
+        assert_eq!(int.value(), Some(15));
+
+        let int = TokenStream::from("0b1010").parse::<LitInt<_>>().unwrap();
+        assert_eq!(int.radix(), 2);
+        assert_eq!(int.value(), Some(10));
+        assert_eq!(int.to_span(), Span::Range(0..6));
+
+        assert_eq!(
+            TokenStream::from("0x").parse::<LitInt<_>>(),
+            Err(UnsynError::Semantics(
+                SemanticsKind::IntDigits,
+                Span::Range(0..2)
+            ))
+        );
+
+        assert_eq!(
+            TokenStream::from("0x_g").parse::<LitInt<_>>(),
+            Err(UnsynError::Semantics(
+                SemanticsKind::IntDigits,
+                Span::Range(0..3)
+            ))
+        );
+
+        assert_eq!(
+            TokenStream::from("-a").parse::<LitInt<_>>(),
+            Err(UnsynError::Syntax(
+                SyntaxKind::Int,
+                ControlFlow::Recovable,
+                Span::Range(1..2)
+            ))
+        );
+    }
+
+    #[test]
+    fn test_float() {
+        assert_eq!(
+            TokenStream::from("3.14").parse::<LitFloat<_>>(),
+            Ok(LitFloat(TokenStream::from((0, "3.14"))))
+        );
+
+        assert_eq!(
+            TokenStream::from("1e10").parse::<LitFloat<_>>(),
+            Ok(LitFloat(TokenStream::from((0, "1e10"))))
+        );
+
+        assert_eq!(
+            TokenStream::from("2.5e-3f64").parse::<LitFloat<_>>(),
+            Ok(LitFloat(TokenStream::from((0, "2.5e-3f64"))))
+        );
+
+        assert_eq!(
+            TokenStream::from("1.;").parse::<LitFloat<_>>(),
+            Ok(LitFloat(TokenStream::from((0, "1."))))
+        );
+
+        assert_eq!(
+            TokenStream::from("1e+").parse::<LitFloat<_>>(),
+            Err(UnsynError::Semantics(
+                SemanticsKind::Float,
+                Span::Range(1..3)
+            ))
+        );
+
+        assert_eq!(
+            TokenStream::from("12").parse::<LitFloat<_>>(),
+            Err(UnsynError::Syntax(
+                SyntaxKind::Float,
+                ControlFlow::Recovable,
+                Span::Range(0..2)
+            ))
+        );
+    }
+
+    #[test]
+    fn test_float_range() {
+        assert_eq!(
+            TokenStream::from("1..2").parse::<LitFloat<_>>(),
+            Err(UnsynError::Syntax(
+                SyntaxKind::Float,
+                ControlFlow::Recovable,
+                Span::Range(0..2)
+            ))
+        );
+
+        assert_eq!(
+            TokenStream::from("1..2").parse::<(LitDec<_>, DotDot<_>, LitDec<_>)>(),
+            Ok((
+                LitDec(TokenStream::from((0, "1"))),
+                DotDot(None, TokenStream::from((1, "..")), None),
+                LitDec(TokenStream::from((3, "2")))
+            ))
+        );
+    }
+
+    #[test]
+    fn test_raw_str() {
+        assert_eq!(
+            TokenStream::from(r#"r"a\b""#).parse::<LitRawStr<_>>(),
+            Ok(LitRawStr {
+                delimiter_start: TokenStream::from((0, "r\"")),
+                content: TokenStream::from((2, r"a\b")),
+                delimiter_end: TokenStream::from((5, "\""))
+            })
+        );
+
+        assert_eq!(
+            TokenStream::from(r##"r#"has "quotes""#"##).parse::<LitRawStr<_>>(),
+            Ok(LitRawStr {
+                delimiter_start: TokenStream::from((0, "r#\"")),
+                content: TokenStream::from((3, r#"has "quotes""#)),
+                delimiter_end: TokenStream::from((15, "\"#"))
+            })
+        );
+
+        assert_eq!(
+            TokenStream::from(r###"r##"a"#b"##"###).parse::<LitRawStr<_>>(),
+            Ok(LitRawStr {
+                delimiter_start: TokenStream::from((0, "r##\"")),
+                content: TokenStream::from((4, r##"a"#b"##)),
+                delimiter_end: TokenStream::from((8, "\"##"))
+            })
+        );
+
+        assert_eq!(
+            TokenStream::from(r##"r#"abc""##).parse::<LitRawStr<_>>(),
+            Err(UnsynError::Semantics(
+                SemanticsKind::RawStr,
+                Span::Range(0..7)
+            ))
+        );
+
+        assert_eq!(
+            TokenStream::from("ra").parse::<LitRawStr<_>>(),
+            Err(UnsynError::Syntax(
+                SyntaxKind::RawStr,
+                ControlFlow::Recovable,
+                Span::Range(1..2)
+            ))
+        );
+    }
+
+    #[test]
+    fn test_char() {
+        assert_eq!(
+            TokenStream::from("'a'").parse::<LitChar<_>>(),
+            Ok(LitChar {
+                delimiter_start: TokenStream::from((0, "'")),
+                content: CharContent::Char(TokenStream::from((1, "a"))),
+                delimiter_end: TokenStream::from((2, "'"))
+            })
+        );
+
+        assert_eq!(
+            TokenStream::from(r"'\n'").parse::<LitChar<_>>(),
+            Ok(LitChar {
+                delimiter_start: TokenStream::from((0, "'")),
+                content: CharContent::ASCIIEscape(ASCIIEscape::LF(TokenStream::from((1, r"\n")))),
+                delimiter_end: TokenStream::from((3, "'"))
+            })
+        );
+
+        assert_eq!(
+            TokenStream::from(r"'\x7f'").parse::<LitChar<_>>(),
+            Ok(LitChar {
+                delimiter_start: TokenStream::from((0, "'")),
+                content: CharContent::ASCIIEscape(ASCIIEscape::Char(TokenStream::from((
+                    1, r"\x7f"
+                )))),
+                delimiter_end: TokenStream::from((5, "'"))
+            })
+        );
+
+        assert_eq!(
+            TokenStream::from(r"'\''").parse::<LitChar<_>>(),
+            Ok(LitChar {
+                delimiter_start: TokenStream::from((0, "'")),
+                content: CharContent::QuoteEscape(QuoteEscape(TokenStream::from((1, r"\'")))),
+                delimiter_end: TokenStream::from((3, "'"))
+            })
+        );
+
+        assert_eq!(
+            TokenStream::from(r"'\u{1F600}'").parse::<LitChar<_>>(),
+            Ok(LitChar {
+                delimiter_start: TokenStream::from((0, "'")),
+                content: CharContent::UnicodeEscape(UnicodeEscape {
+                    delimiter_start: TokenStream::from((1, r"\u{")),
+                    digits: TokenStream::from((4, "1F600")),
+                    delimiter_end: TokenStream::from((9, "}"))
+                }),
+                delimiter_end: TokenStream::from((10, "'"))
+            })
+        );
+    }
+
+    #[test]
+    fn test_char_errors() {
+        assert_eq!(
+            TokenStream::from("''").parse::<LitChar<_>>(),
+            Err(UnsynError::Semantics(
+                SemanticsKind::CharEmpty,
+                Span::Range(0..2)
+            ))
+        );
+
+        assert_eq!(
+            TokenStream::from("'ab'").parse::<LitChar<_>>(),
+            Err(UnsynError::Semantics(
+                SemanticsKind::CharTooLong,
+                Span::Range(0..4)
+            ))
+        );
+
+        assert_eq!(
+            TokenStream::from("'static").parse::<LitChar<_>>(),
+            Err(UnsynError::Syntax(
+                SyntaxKind::Char,
+                ControlFlow::Recovable,
+                Span::Range(0..7)
+            ))
+        );
+
+        assert_eq!(
+            TokenStream::from("'a + 'b").parse::<LitChar<_>>(),
+            Err(UnsynError::Syntax(
+                SyntaxKind::Char,
+                ControlFlow::Recovable,
+                Span::Range(0..2)
+            ))
+        );
+    }
 }
@@ -9,3 +9,4 @@ pub mod ident;
 pub mod keyword;
 pub mod lit;
 pub mod punct;
+pub mod trivia;
@@ -2,9 +2,7 @@
 
 macro_rules! define_punct {
     ($ident: ident, $value: literal) => {
-        #[doc = "define punct `"]
-        #[doc = stringify!($value)]
-        #[doc = "`"]
+        #[doc = concat!("define punct `", $value, "`")]
         #[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Clone)]
         #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
         pub struct $ident<I>(pub Option<super::S<I>>, pub I, pub Option<super::S<I>>)
@@ -34,6 +32,31 @@ macro_rules! define_punct {
                 self.0.to_span() + self.1.to_span()
             }
         }
+
+        impl<I> parserc::syntax::ToSource for $ident<I>
+        where
+            I: crate::input::UnsynInput + parserc::syntax::ToSource,
+        {
+            #[inline]
+            fn to_source(&self, out: &mut String) {
+                self.0.to_source(out);
+                self.1.to_source(out);
+                self.2.to_source(out);
+            }
+        }
+
+        impl<'a> crate::incremental::Rebase<'a> for $ident<crate::input::TokenStream<'_>> {
+            type Output = $ident<crate::input::TokenStream<'a>>;
+
+            #[inline]
+            fn rebase(&self, source: &'a str, delta: isize) -> Option<Self::Output> {
+                Some($ident(
+                    self.0.rebase(source, delta)?,
+                    self.1.rebase(source, delta)?,
+                    self.2.rebase(source, delta)?,
+                ))
+            }
+        }
     };
 }
 
@@ -56,3 +79,4 @@ define_punct!(Comma, ",");
 define_punct!(ArrowRight, "->");
 define_punct!(Semi, ";");
 define_punct!(DotDot, "..");
+define_punct!(DotDotEq, "..=");
@@ -0,0 +1,74 @@
+//! Trivia tokens skipped between grammar rules.
+//!
+//! Trivia are whitespace characters, [`LineComment`]s and [`BlockComment`]s. Documents
+//! (`///`, `//!`, `/** */`, `/*! */`) are not trivia, they are meaningful to the syntax tree.
+
+use parserc::{Parser, syntax::Syntax};
+
+use crate::{
+    input::UnsynInput,
+    lexical::{
+        S,
+        comments::{BlockComment, LineComment},
+    },
+};
+
+/// Skips any trivia before running the inner `parser`.
+#[inline]
+pub fn skip_trivia<I, P>(parser: P) -> impl Parser<I, Output = P::Output>
+where
+    I: UnsynInput,
+    P: Parser<I>,
+{
+    move |input: &mut I| {
+        loop {
+            if S::into_parser().ok().parse(input)?.is_some() {
+                continue;
+            }
+
+            if LineComment::into_parser().ok().parse(input)?.is_some() {
+                continue;
+            }
+
+            if BlockComment::into_parser().ok().parse(input)?.is_some() {
+                continue;
+            }
+
+            break;
+        }
+
+        parser.parse(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{input::TokenStream, lexical::keyword::Use};
+
+    use super::*;
+
+    #[test]
+    fn test_line_comment() {
+        assert_eq!(
+            skip_trivia(Use::into_parser()).parse(&mut TokenStream::from("// hello\n use")),
+            Ok(Use(TokenStream::from((10, "use")), None))
+        );
+    }
+
+    #[test]
+    fn test_block_comment() {
+        assert_eq!(
+            skip_trivia(Use::into_parser()).parse(&mut TokenStream::from("/* a /* b */ */\nuse")),
+            Ok(Use(TokenStream::from((16, "use")), None))
+        );
+    }
+
+    #[test]
+    fn test_doc_is_not_trivia() {
+        assert!(
+            skip_trivia(Use::into_parser())
+                .parse(&mut TokenStream::from("/// hello\nuse"))
+                .is_err()
+        );
+    }
+}
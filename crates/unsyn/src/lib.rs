@@ -1,7 +1,12 @@
 //！ A DSL for specifying concrete syntax trees.
 
+#[macro_use]
+mod nodes;
+
 pub mod errors;
+pub mod incremental;
 pub mod input;
 pub mod lexical;
 pub mod semantics;
+pub mod source;
 pub mod syntax;
@@ -0,0 +1,155 @@
+//! The list of `unsyn` syntax nodes, shared by the trait impls generated per node.
+
+/// Invokes the macro `$callback` once per syntax node, as `struct Name { fields }` or
+/// `enum Name { Variant(fields), Variant { fields } }`, with the fields in declaration order.
+///
+/// The node types must be in scope at the call site.
+macro_rules! for_each_node {
+    ($callback: ident) => {
+        $callback!(struct S { 0 });
+        $callback!(struct LineComment { 0 });
+        $callback!(struct InnerLineDoc { 0 });
+        $callback!(struct OuterLineDoc { 0 });
+        $callback!(struct BlockComment { 0 });
+        $callback!(struct InnerBlockDoc { 0 });
+        $callback!(struct OuterBlockDoc { 0 });
+        $callback!(struct Ident { 0 });
+        $callback!(struct Lifetime { 0 });
+
+        $callback!(
+            enum ASCIIEscape {
+                LF(a),
+                CR(a),
+                Tab(a),
+                BlackSlash(a),
+                Null(a),
+                Char(a),
+            }
+        );
+        $callback!(struct UnicodeEscape { delimiter_start, digits, delimiter_end });
+        $callback!(struct QuoteEscape { 0 });
+        $callback!(
+            enum StrSegment {
+                QuoteEscape(a),
+                ASCIIEscape(a),
+                UnicodeEscape(a),
+                CharWithException(a),
+            }
+        );
+        $callback!(struct LitStr { delimiter_start, content, delimiter_end });
+        $callback!(struct LitUnicode { 0 });
+        $callback!(struct LitDec { 0 });
+        $callback!(struct LitInt { sign, prefix, digits });
+        $callback!(struct LitFloat { 0 });
+        $callback!(struct LitRawStr { delimiter_start, content, delimiter_end });
+        $callback!(
+            enum CharContent {
+                QuoteEscape(a),
+                ASCIIEscape(a),
+                UnicodeEscape(a),
+                Char(a),
+            }
+        );
+        $callback!(struct LitChar { delimiter_start, content, delimiter_end });
+
+        $callback!(struct Path { leading_sep, segments });
+        $callback!(
+            enum PathSegment {
+                This(a),
+                Super(a),
+                Crate(a),
+                Ident(a),
+            }
+        );
+
+        $callback!(struct UseDeclaration { keyword, use_tree });
+        $callback!(enum UseTree {
+            Star { prefix, star },
+            Group { prefix, group },
+            Path(a, b),
+        });
+        $callback!(struct ModuleDeclaration { keyword, ident });
+
+        $callback!(enum Stmt {
+            Whitespace { keyword, ident, arrow_right, expr, semi },
+            Lexer { keyword, ident, arrow_right, expr, semi },
+            Syntax { keyword, ident, arrow_right, expr, semi },
+        });
+        $callback!(struct Expr { alts });
+        $callback!(struct ExprNoTopAlts { exprs });
+        $callback!(
+            enum ExprNoTopAlt {
+                WithSuffix(a),
+                WithoutSuffix(a),
+            }
+        );
+        $callback!(enum ExprWithSuffix {
+            Star(a, b),
+            Question(a, b),
+            Plus(a, b),
+            Repeat { target, suffix },
+            Concat { target, s, keyword, suffix },
+            Followed { target, s, keyword, suffix },
+            Except { target, s, keyword, tokens },
+        });
+        $callback!(
+            enum ExprWithoutSuffix {
+                Tilde(a, b),
+                Call(a),
+                Paren(a),
+                Set(a),
+                Str(a),
+                Unicode(a),
+                Path(a),
+            }
+        );
+        $callback!(
+            enum SetItem {
+                Range(a),
+                Str(a),
+                Unicode(a),
+                Path(a),
+            }
+        );
+        $callback!(
+            enum Range {
+                Unicode(a, b, c),
+                Str(a, b, c),
+                UnicodeInclusive(a, b, c),
+                StrInclusive(a, b, c),
+            }
+        );
+        $callback!(
+            enum Repeat {
+                RangeToInclusive(a, b),
+                RangeInclusive(a, b, c),
+                RangeTo(a, b),
+                RangeFrom(a, b, c),
+                Count(a),
+            }
+        );
+
+        $callback!(
+            enum InnerDoc {
+                Block(a, b),
+                Line(a, b),
+            }
+        );
+        $callback!(
+            enum OuterDoc {
+                Block(a, b),
+                Line(a, b),
+            }
+        );
+        $callback!(
+            enum Item {
+                OuterDoc(a),
+                S(a),
+                Use(a, b),
+                Mod(a, b),
+                Stmt(a),
+            }
+        );
+        $callback!(struct Crate { inner_docs, items });
+    };
+}
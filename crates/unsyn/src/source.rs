@@ -0,0 +1,119 @@
+//! Source reconstruction for `unsyn` syntax trees.
+//!
+//! Every token keeps its raw input slice, including the surrounding whitespace,
+//! so concatenating the slices in order reproduces the parsed source exactly.
+
+use parserc::syntax::ToSource;
+
+use crate::{
+    input::UnsynInput,
+    lexical::{
+        S,
+        comments::{
+            BlockComment, InnerBlockDoc, InnerLineDoc, LineComment, OuterBlockDoc, OuterLineDoc,
+        },
+        ident::{Ident, Lifetime},
+        lit::{
+            ASCIIEscape, CharContent, LitChar, LitDec, LitFloat, LitInt, LitRawStr, LitStr,
+            LitUnicode, QuoteEscape, StrSegment, UnicodeEscape,
+        },
+    },
+    syntax::{
+        Crate, Expr, ExprNoTopAlt, ExprNoTopAlts, ExprWithSuffix, ExprWithoutSuffix, InnerDoc,
+        Item, ModuleDeclaration, OuterDoc, Path, PathSegment, Range, Repeat, SetItem, Stmt,
+        UseDeclaration, UseTree,
+    },
+};
+
+/// Implements [`ToSource`] for a syntax node by writing its fields in declaration order.
+macro_rules! impl_to_source {
+    (struct $name: ident { $($field: tt),* $(,)? }) => {
+        impl<I> ToSource for $name<I>
+        where
+            I: UnsynInput + ToSource,
+        {
+            #[inline]
+            fn to_source(&self, out: &mut String) {
+                $(ToSource::to_source(&self.$field, out);)*
+            }
+        }
+    };
+    (enum $name: ident { $($variant: ident $(($($tuple: ident),*))? $({$($named: ident),*})?),* $(,)? }) => {
+        impl<I> ToSource for $name<I>
+        where
+            I: UnsynInput + ToSource,
+        {
+            #[inline]
+            fn to_source(&self, out: &mut String) {
+                match self {
+                    $(
+                        $name::$variant $(($($tuple),*))? $({$($named),*})? => {
+                            $($(ToSource::to_source($tuple, out);)*)?
+                            $($(ToSource::to_source($named, out);)*)?
+                        }
+                    )*
+                }
+            }
+        }
+    };
+}
+
+for_each_node!(impl_to_source);
+
+macro_rules! impl_to_source_string {
+    ($($name: ident),*) => {
+        $(
+            impl<I> $name<I>
+            where
+                I: UnsynInput + ToSource,
+            {
+                /// Reconstructs the source code of this node.
+                pub fn to_source(&self) -> String {
+                    let mut out = String::new();
+                    ToSource::to_source(self, &mut out);
+                    out
+                }
+            }
+        )*
+    };
+}
+
+impl_to_source_string!(Crate, Item, Stmt);
+
+#[cfg(test)]
+mod tests {
+    use parserc::syntax::SyntaxInput;
+
+    use super::*;
+    use crate::input::TokenStream;
+
+    #[test]
+    fn test_round_trip() {
+        for source in [
+            "//! unsyn for `unsyn`\n\nuse a::*;\n",
+            "use a::b::{c, d, f::g::*};\nmod m;",
+            "/// doc\nlexer OCT_DIGIT -> ['0'-'7'] ;\n",
+            "whitespace WHITESPACE ->\n      U+0009\n    | U+000A;\n",
+            "syntax A -> ( B | C )? D* ~E <XID_Start> followed F except 'x' G{2..=5};",
+            "lexer RAW_IDENTIFIER -> 'r#' IDENTIFIER_OR_KEYWORD;",
+        ] {
+            let c = TokenStream::from(source).parse::<Crate<_>>().unwrap();
+
+            assert_eq!(c.to_source(), source);
+        }
+    }
+
+    #[test]
+    fn test_item_to_source() {
+        let c = TokenStream::from("use a;\nlexer A -> 'a';")
+            .parse::<Crate<_>>()
+            .unwrap();
+
+        let Some(Item::Stmt(stmt)) = c.items.last() else {
+            panic!("expect stmt, got {:?}", c.items);
+        };
+
+        assert_eq!(stmt.to_source(), "lexer A -> 'a';");
+        assert_eq!(c.items[0].to_source(), "use a;\n");
+    }
+}
@@ -1,6 +1,6 @@
 use parserc::{
     BeforeSpan, ControlFlow,
-    syntax::{Punctuated, Syntax},
+    syntax::{Punctuated, SeparatedNonEmpty, Syntax},
 };
 
 use crate::{
@@ -12,7 +12,9 @@ use crate::{
         ident::Ident,
         keyword::{Concat, Except, Followed, Lexer, Whitespace},
         lit::{LitDec, LitStr, LitUnicode},
-        punct::{ArrowRight, Comma, DotDot, Minus, Or, Plus, Question, Semi, Star, Tilde},
+        punct::{
+            ArrowRight, Comma, DotDot, DotDotEq, Minus, Or, Plus, Question, Semi, Star, Tilde,
+        },
     },
     syntax::Path,
 };
@@ -74,8 +76,8 @@ pub struct Expr<I>
 where
     I: UnsynInput,
 {
-    pub first: ExprNoTopAlts<I>,
-    pub rest: Vec<(Or<I>, ExprNoTopAlts<I>)>,
+    /// alternative expressions separated by `|`.
+    pub alts: SeparatedNonEmpty<ExprNoTopAlts<I>, Or<I>>,
 }
 
 #[inline]
@@ -95,10 +97,8 @@ pub struct ExprNoTopAlts<I>
 where
     I: UnsynInput,
 {
-    /// first expr,
-    pub first: ExprNoTopAlt<I>,
-    /// rest expr list.
-    pub rest: Vec<(Option<S<I>>, ExprNoTopAlt<I>)>,
+    /// expr list separated by optional whitespaces.
+    pub exprs: SeparatedNonEmpty<ExprNoTopAlt<I>, Option<S<I>>>,
 }
 
 /// No top alt expression.
@@ -234,15 +234,25 @@ where
 {
     Unicode(LitUnicode<I>, #[parserc(crucial)] Minus<I>, LitUnicode<I>),
     Str(LitStr<I>, #[parserc(crucial)] Minus<I>, LitStr<I>),
+    UnicodeInclusive(
+        LitUnicode<I>,
+        #[parserc(crucial)] DotDotEq<I>,
+        LitUnicode<I>,
+    ),
+    StrInclusive(LitStr<I>, #[parserc(crucial)] DotDotEq<I>, LitStr<I>),
 }
 
 /// The suffix of repeat expresison.
+///
+/// The inclusive variants are listed first, so `..=` is preferred over `..`.
 #[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Clone, Syntax)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Repeat<I>
 where
     I: UnsynInput,
 {
+    RangeToInclusive(DotDotEq<I>, LitDec<I>),
+    RangeInclusive(LitDec<I>, DotDotEq<I>, LitDec<I>),
     RangeTo(DotDot<I>, LitDec<I>),
     RangeFrom(LitDec<I>, DotDot<I>, Option<LitDec<I>>),
     Count(LitDec<I>),
@@ -272,4 +282,54 @@ mod tests {
             TokenStream::from(r#"lexer OCT_DIGIT -> ['0'-'7'];"#).parse::<Stmt<_>>()
         );
     }
+
+    #[test]
+    fn test_repeat_inclusive() {
+        assert_eq!(
+            TokenStream::from("2..=5").parse::<Repeat<_>>(),
+            Ok(Repeat::RangeInclusive(
+                LitDec(TokenStream::from((0, "2"))),
+                DotDotEq(None, TokenStream::from((1, "..=")), None),
+                LitDec(TokenStream::from((4, "5")))
+            ))
+        );
+
+        assert_eq!(
+            TokenStream::from("..=5").parse::<Repeat<_>>(),
+            Ok(Repeat::RangeToInclusive(
+                DotDotEq(None, TokenStream::from((0, "..=")), None),
+                LitDec(TokenStream::from((3, "5")))
+            ))
+        );
+
+        assert!(matches!(
+            TokenStream::from("a{2..=5}").parse::<Expr<_>>(),
+            Ok(Expr { alts }) if alts.rest.is_empty()
+        ));
+    }
+
+    #[test]
+    fn test_range_inclusive() {
+        assert_eq!(
+            TokenStream::from("U+0041..=U+005A").parse::<Range<_>>(),
+            Ok(Range::UnicodeInclusive(
+                LitUnicode(TokenStream::from((0, "U+0041"))),
+                DotDotEq(None, TokenStream::from((6, "..=")), None),
+                LitUnicode(TokenStream::from((9, "U+005A")))
+            ))
+        );
+
+        let expr = TokenStream::from("['a'..='z']")
+            .parse::<ExprWithoutSuffix<_>>()
+            .unwrap();
+
+        let ExprWithoutSuffix::Set(set) = expr else {
+            panic!("expect set expr, got {:?}", expr);
+        };
+
+        assert!(matches!(
+            set.body.iter().next(),
+            Some(SetItem::Range(Range::StrInclusive(..)))
+        ));
+    }
 }
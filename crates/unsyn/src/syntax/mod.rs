@@ -11,3 +11,6 @@ pub use expr::*;
 
 mod item;
 pub use item::*;
+
+mod visit;
+pub use visit::*;
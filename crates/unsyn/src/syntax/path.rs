@@ -1,4 +1,4 @@
-use parserc::syntax::Syntax;
+use parserc::syntax::{SeparatedNonEmpty, Syntax};
 
 use crate::{
     input::UnsynInput,
@@ -18,10 +18,8 @@ where
 {
     /// leading optional path separator
     pub leading_sep: Option<PathSep<I>>,
-    /// first segment.
-    pub first: PathSegment<I>,
-    /// rest segments.
-    pub rest: Vec<(PathSep<I>, PathSegment<I>)>,
+    /// path segments separated by `::`.
+    pub segments: SeparatedNonEmpty<PathSegment<I>, PathSep<I>>,
 }
 
 /// Segment of path.
@@ -36,3 +34,67 @@ where
     Crate(Crate<I>),
     Ident(Ident<I>),
 }
+
+#[cfg(test)]
+mod tests {
+    use parserc::syntax::SyntaxInput;
+
+    use crate::input::TokenStream;
+
+    use super::*;
+
+    #[test]
+    fn test_path() {
+        assert_eq!(
+            TokenStream::from("a::b").parse::<Path<_>>(),
+            Ok(Path {
+                leading_sep: None,
+                segments: SeparatedNonEmpty {
+                    first: PathSegment::Ident(Ident(TokenStream::from((0, "a")))),
+                    rest: vec![(
+                        PathSep(None, TokenStream::from((1, "::")), None),
+                        PathSegment::Ident(Ident(TokenStream::from((3, "b"))))
+                    )]
+                }
+            })
+        );
+    }
+
+    #[test]
+    fn test_leading_sep() {
+        let path = TokenStream::from("::crate::a").parse::<Path<_>>().unwrap();
+
+        assert_eq!(
+            path.leading_sep,
+            Some(PathSep(None, TokenStream::from((0, "::")), None))
+        );
+        assert_eq!(
+            path.segments.iter().cloned().collect::<Vec<_>>(),
+            vec![
+                PathSegment::Crate(Crate(TokenStream::from((2, "crate")), None)),
+                PathSegment::Ident(Ident(TokenStream::from((9, "a"))))
+            ]
+        );
+    }
+
+    #[test]
+    fn test_trailing_sep() {
+        let mut input = TokenStream::from("this::a::{");
+
+        assert_eq!(
+            input.parse::<Path<_>>(),
+            Ok(Path {
+                leading_sep: None,
+                segments: SeparatedNonEmpty {
+                    first: PathSegment::This(This(TokenStream::from((0, "this")), None)),
+                    rest: vec![(
+                        PathSep(None, TokenStream::from((4, "::")), None),
+                        PathSegment::Ident(Ident(TokenStream::from((6, "a"))))
+                    )]
+                }
+            })
+        );
+
+        assert_eq!(input, TokenStream::from((7, "::{")));
+    }
+}
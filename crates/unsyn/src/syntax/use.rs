@@ -69,7 +69,7 @@ where
 
 #[cfg(test)]
 mod tests {
-    use parserc::syntax::{Delimiter, SyntaxInput};
+    use parserc::syntax::{Delimiter, SeparatedNonEmpty, SyntaxInput};
 
     use super::*;
     use crate::{
@@ -95,11 +95,13 @@ mod tests {
                     prefix: Some((
                         Some(Path {
                             leading_sep: None,
-                            first: PathSegment::Ident(Ident(TokenStream::from((4, "a")))),
-                            rest: vec![(
-                                PathSep(None, TokenStream::from((5, "::")), None),
-                                PathSegment::Ident(Ident(TokenStream::from((7, "b"))))
-                            )]
+                            segments: SeparatedNonEmpty {
+                                first: PathSegment::Ident(Ident(TokenStream::from((4, "a")))),
+                                rest: vec![(
+                                    PathSep(None, TokenStream::from((5, "::")), None),
+                                    PathSegment::Ident(Ident(TokenStream::from((7, "b"))))
+                                )]
+                            }
                         }),
                         PathSep(None, TokenStream::from((8, "::")), None)
                     )),
@@ -112,10 +114,12 @@ mod tests {
                                     UseTree::Path(
                                         Path {
                                             leading_sep: None,
-                                            first: PathSegment::Ident(Ident(TokenStream::from((
-                                                11, "c"
-                                            )))),
-                                            rest: vec![]
+                                            segments: SeparatedNonEmpty {
+                                                first: PathSegment::Ident(Ident(
+                                                    TokenStream::from((11, "c"))
+                                                )),
+                                                rest: vec![]
+                                            }
                                         },
                                         None
                                     ),
@@ -129,10 +133,12 @@ mod tests {
                                     UseTree::Path(
                                         Path {
                                             leading_sep: None,
-                                            first: PathSegment::Ident(Ident(TokenStream::from((
-                                                14, "d"
-                                            )))),
-                                            rest: vec![]
+                                            segments: SeparatedNonEmpty {
+                                                first: PathSegment::Ident(Ident(
+                                                    TokenStream::from((14, "d"))
+                                                )),
+                                                rest: vec![]
+                                            }
                                         },
                                         None
                                     ),
@@ -146,15 +152,21 @@ mod tests {
                                     UseTree::Path(
                                         Path {
                                             leading_sep: None,
-                                            first: PathSegment::Ident(Ident(TokenStream::from((
-                                                17, "e"
-                                            )))),
-                                            rest: vec![(
-                                                PathSep(None, TokenStream::from((18, "::")), None),
-                                                PathSegment::Ident(Ident(TokenStream::from((
-                                                    20, "f"
-                                                ))))
-                                            )]
+                                            segments: SeparatedNonEmpty {
+                                                first: PathSegment::Ident(Ident(
+                                                    TokenStream::from((17, "e"))
+                                                )),
+                                                rest: vec![(
+                                                    PathSep(
+                                                        None,
+                                                        TokenStream::from((18, "::")),
+                                                        None
+                                                    ),
+                                                    PathSegment::Ident(Ident(TokenStream::from((
+                                                        20, "f"
+                                                    ))))
+                                                )]
+                                            }
                                         },
                                         None
                                     ),
@@ -169,13 +181,17 @@ mod tests {
                                 prefix: Some((
                                     Some(Path {
                                         leading_sep: None,
-                                        first: PathSegment::Ident(Ident(TokenStream::from((
-                                            23, "g"
-                                        )))),
-                                        rest: vec![(
-                                            PathSep(None, TokenStream::from((24, "::")), None),
-                                            PathSegment::Ident(Ident(TokenStream::from((26, "h"))))
-                                        )]
+                                        segments: SeparatedNonEmpty {
+                                            first: PathSegment::Ident(Ident(TokenStream::from((
+                                                23, "g"
+                                            )))),
+                                            rest: vec![(
+                                                PathSep(None, TokenStream::from((24, "::")), None),
+                                                PathSegment::Ident(Ident(TokenStream::from((
+                                                    26, "h"
+                                                ))))
+                                            )]
+                                        }
                                     }),
                                     PathSep(None, TokenStream::from((27, "::")), None)
                                 )),
@@ -199,11 +215,13 @@ mod tests {
                     prefix: Some((
                         Some(Path {
                             leading_sep: None,
-                            first: PathSegment::Ident(Ident(TokenStream::from((4, "a")))),
-                            rest: vec![(
-                                PathSep(None, TokenStream::from((5, "::")), None),
-                                PathSegment::Ident(Ident(TokenStream::from((7, "b"))))
-                            )]
+                            segments: SeparatedNonEmpty {
+                                first: PathSegment::Ident(Ident(TokenStream::from((4, "a")))),
+                                rest: vec![(
+                                    PathSep(None, TokenStream::from((5, "::")), None),
+                                    PathSegment::Ident(Ident(TokenStream::from((7, "b"))))
+                                )]
+                            }
                         }),
                         PathSep(None, TokenStream::from((8, "::")), None)
                     )),
@@ -216,11 +234,13 @@ mod tests {
                                     UseTree::Path(
                                         Path {
                                             leading_sep: None,
-                                            first: PathSegment::This(This(
-                                                TokenStream::from((11, "this")),
-                                                Some(S(TokenStream::from((15, " "))))
-                                            )),
-                                            rest: vec![]
+                                            segments: SeparatedNonEmpty {
+                                                first: PathSegment::This(This(
+                                                    TokenStream::from((11, "this")),
+                                                    Some(S(TokenStream::from((15, " "))))
+                                                )),
+                                                rest: vec![]
+                                            }
                                         },
                                         Some((
                                             As(
@@ -240,10 +260,12 @@ mod tests {
                                     UseTree::Path(
                                         Path {
                                             leading_sep: None,
-                                            first: PathSegment::Ident(Ident(TokenStream::from((
-                                                23, "c"
-                                            )))),
-                                            rest: vec![]
+                                            segments: SeparatedNonEmpty {
+                                                first: PathSegment::Ident(Ident(
+                                                    TokenStream::from((23, "c"))
+                                                )),
+                                                rest: vec![]
+                                            }
                                         },
                                         None
                                     ),
@@ -258,10 +280,12 @@ mod tests {
                                 prefix: Some((
                                     Some(Path {
                                         leading_sep: None,
-                                        first: PathSegment::Ident(Ident(TokenStream::from((
-                                            26, "d"
-                                        )))),
-                                        rest: vec![]
+                                        segments: SeparatedNonEmpty {
+                                            first: PathSegment::Ident(Ident(TokenStream::from((
+                                                26, "d"
+                                            )))),
+                                            rest: vec![]
+                                        }
                                     }),
                                     PathSep(None, TokenStream::from((27, "::")), None)
                                 )),
@@ -287,19 +311,21 @@ mod tests {
                                         tail: Some(Box::new(UseTree::Path(
                                             Path {
                                                 leading_sep: None,
-                                                first: PathSegment::Ident(Ident(
-                                                    TokenStream::from((33, "e"))
-                                                )),
-                                                rest: vec![(
-                                                    PathSep(
-                                                        None,
-                                                        TokenStream::from((34, "::")),
-                                                        None
-                                                    ),
-                                                    PathSegment::Ident(Ident(TokenStream::from((
-                                                        36, "f"
-                                                    ))))
-                                                )]
+                                                segments: SeparatedNonEmpty {
+                                                    first: PathSegment::Ident(Ident(
+                                                        TokenStream::from((33, "e"))
+                                                    )),
+                                                    rest: vec![(
+                                                        PathSep(
+                                                            None,
+                                                            TokenStream::from((34, "::")),
+                                                            None
+                                                        ),
+                                                        PathSegment::Ident(Ident(
+                                                            TokenStream::from((36, "f"))
+                                                        ))
+                                                    )]
+                                                }
                                             },
                                             None
                                         )))
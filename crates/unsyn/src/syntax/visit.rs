@@ -0,0 +1,401 @@
+use crate::{
+    input::UnsynInput,
+    lexical::{
+        ident::Ident,
+        lit::{LitDec, LitStr, LitUnicode},
+    },
+    syntax::{
+        Crate, Expr, ExprNoTopAlt, ExprNoTopAlts, ExprWithSuffix, ExprWithoutSuffix, InnerDoc,
+        Item, ModuleDeclaration, OuterDoc, Path, PathSegment, Range, Repeat, SetItem, Stmt,
+        UseDeclaration, UseTree,
+    },
+};
+
+/// Syntax tree traversal over shared borrowed nodes.
+///
+/// Each `visit_*` method defaults to the matching `walk_*` function, which recurses into
+/// the children of the node. Override a method to inspect a node, and call the `walk_*`
+/// function from it to keep visiting the children.
+pub trait Visit<'ast, I>
+where
+    I: UnsynInput,
+{
+    fn visit_crate(&mut self, node: &'ast Crate<I>) {
+        walk_crate(self, node);
+    }
+
+    fn visit_inner_doc(&mut self, node: &'ast InnerDoc<I>) {
+        _ = node;
+    }
+
+    fn visit_outer_doc(&mut self, node: &'ast OuterDoc<I>) {
+        _ = node;
+    }
+
+    fn visit_item(&mut self, node: &'ast Item<I>) {
+        walk_item(self, node);
+    }
+
+    fn visit_use_declaration(&mut self, node: &'ast UseDeclaration<I>) {
+        walk_use_declaration(self, node);
+    }
+
+    fn visit_use_tree(&mut self, node: &'ast UseTree<I>) {
+        walk_use_tree(self, node);
+    }
+
+    fn visit_module_declaration(&mut self, node: &'ast ModuleDeclaration<I>) {
+        walk_module_declaration(self, node);
+    }
+
+    fn visit_stmt(&mut self, node: &'ast Stmt<I>) {
+        walk_stmt(self, node);
+    }
+
+    fn visit_expr(&mut self, node: &'ast Expr<I>) {
+        walk_expr(self, node);
+    }
+
+    fn visit_expr_no_top_alts(&mut self, node: &'ast ExprNoTopAlts<I>) {
+        walk_expr_no_top_alts(self, node);
+    }
+
+    fn visit_expr_no_top_alt(&mut self, node: &'ast ExprNoTopAlt<I>) {
+        walk_expr_no_top_alt(self, node);
+    }
+
+    fn visit_expr_with_suffix(&mut self, node: &'ast ExprWithSuffix<I>) {
+        walk_expr_with_suffix(self, node);
+    }
+
+    fn visit_expr_without_suffix(&mut self, node: &'ast ExprWithoutSuffix<I>) {
+        walk_expr_without_suffix(self, node);
+    }
+
+    fn visit_set_item(&mut self, node: &'ast SetItem<I>) {
+        walk_set_item(self, node);
+    }
+
+    fn visit_range(&mut self, node: &'ast Range<I>) {
+        walk_range(self, node);
+    }
+
+    fn visit_repeat(&mut self, node: &'ast Repeat<I>) {
+        walk_repeat(self, node);
+    }
+
+    fn visit_path(&mut self, node: &'ast Path<I>) {
+        walk_path(self, node);
+    }
+
+    fn visit_path_segment(&mut self, node: &'ast PathSegment<I>) {
+        walk_path_segment(self, node);
+    }
+
+    fn visit_ident(&mut self, node: &'ast Ident<I>) {
+        _ = node;
+    }
+
+    fn visit_lit_str(&mut self, node: &'ast LitStr<I>) {
+        _ = node;
+    }
+
+    fn visit_lit_unicode(&mut self, node: &'ast LitUnicode<I>) {
+        _ = node;
+    }
+
+    fn visit_lit_dec(&mut self, node: &'ast LitDec<I>) {
+        _ = node;
+    }
+}
+
+/// Visits the children of [`Crate`].
+pub fn walk_crate<'ast, V, I>(v: &mut V, node: &'ast Crate<I>)
+where
+    V: Visit<'ast, I> + ?Sized,
+    I: UnsynInput,
+{
+    for doc in &node.inner_docs {
+        v.visit_inner_doc(doc);
+    }
+
+    for item in &node.items {
+        v.visit_item(item);
+    }
+}
+
+/// Visits the children of [`Item`].
+pub fn walk_item<'ast, V, I>(v: &mut V, node: &'ast Item<I>)
+where
+    V: Visit<'ast, I> + ?Sized,
+    I: UnsynInput,
+{
+    match node {
+        Item::OuterDoc(doc) => v.visit_outer_doc(doc),
+        Item::S(_) => {}
+        Item::Use(decl, _) => v.visit_use_declaration(decl),
+        Item::Mod(decl, _) => v.visit_module_declaration(decl),
+        Item::Stmt(stmt) => v.visit_stmt(stmt),
+    }
+}
+
+/// Visits the children of [`UseDeclaration`].
+pub fn walk_use_declaration<'ast, V, I>(v: &mut V, node: &'ast UseDeclaration<I>)
+where
+    V: Visit<'ast, I> + ?Sized,
+    I: UnsynInput,
+{
+    v.visit_use_tree(&node.use_tree);
+}
+
+/// Visits the children of [`UseTree`].
+pub fn walk_use_tree<'ast, V, I>(v: &mut V, node: &'ast UseTree<I>)
+where
+    V: Visit<'ast, I> + ?Sized,
+    I: UnsynInput,
+{
+    match node {
+        UseTree::Star { prefix, .. } => {
+            if let Some((Some(path), _)) = prefix {
+                v.visit_path(path);
+            }
+        }
+        UseTree::Group { prefix, group } => {
+            if let Some((Some(path), _)) = prefix {
+                v.visit_path(path);
+            }
+
+            for tree in &group.body {
+                v.visit_use_tree(tree);
+            }
+        }
+        UseTree::Path(path, rename) => {
+            v.visit_path(path);
+
+            if let Some((_, ident)) = rename {
+                v.visit_ident(ident);
+            }
+        }
+    }
+}
+
+/// Visits the children of [`ModuleDeclaration`].
+pub fn walk_module_declaration<'ast, V, I>(v: &mut V, node: &'ast ModuleDeclaration<I>)
+where
+    V: Visit<'ast, I> + ?Sized,
+    I: UnsynInput,
+{
+    v.visit_ident(&node.ident);
+}
+
+/// Visits the children of [`Stmt`].
+pub fn walk_stmt<'ast, V, I>(v: &mut V, node: &'ast Stmt<I>)
+where
+    V: Visit<'ast, I> + ?Sized,
+    I: UnsynInput,
+{
+    match node {
+        Stmt::Whitespace { ident, expr, .. }
+        | Stmt::Lexer { ident, expr, .. }
+        | Stmt::Syntax { ident, expr, .. } => {
+            v.visit_ident(ident);
+            v.visit_expr(expr);
+        }
+    }
+}
+
+/// Visits the children of [`Expr`].
+pub fn walk_expr<'ast, V, I>(v: &mut V, node: &'ast Expr<I>)
+where
+    V: Visit<'ast, I> + ?Sized,
+    I: UnsynInput,
+{
+    for alt in node.alts.iter() {
+        v.visit_expr_no_top_alts(alt);
+    }
+}
+
+/// Visits the children of [`ExprNoTopAlts`].
+pub fn walk_expr_no_top_alts<'ast, V, I>(v: &mut V, node: &'ast ExprNoTopAlts<I>)
+where
+    V: Visit<'ast, I> + ?Sized,
+    I: UnsynInput,
+{
+    for expr in node.exprs.iter() {
+        v.visit_expr_no_top_alt(expr);
+    }
+}
+
+/// Visits the children of [`ExprNoTopAlt`].
+pub fn walk_expr_no_top_alt<'ast, V, I>(v: &mut V, node: &'ast ExprNoTopAlt<I>)
+where
+    V: Visit<'ast, I> + ?Sized,
+    I: UnsynInput,
+{
+    match node {
+        ExprNoTopAlt::WithSuffix(expr) => v.visit_expr_with_suffix(expr),
+        ExprNoTopAlt::WithoutSuffix(expr) => v.visit_expr_without_suffix(expr),
+    }
+}
+
+/// Visits the children of [`ExprWithSuffix`].
+pub fn walk_expr_with_suffix<'ast, V, I>(v: &mut V, node: &'ast ExprWithSuffix<I>)
+where
+    V: Visit<'ast, I> + ?Sized,
+    I: UnsynInput,
+{
+    match node {
+        ExprWithSuffix::Star(target, _)
+        | ExprWithSuffix::Question(target, _)
+        | ExprWithSuffix::Plus(target, _) => v.visit_expr_without_suffix(target),
+        ExprWithSuffix::Repeat { target, suffix } => {
+            v.visit_expr_without_suffix(target);
+            v.visit_repeat(&suffix.body);
+        }
+        ExprWithSuffix::Concat { target, suffix, .. }
+        | ExprWithSuffix::Followed { target, suffix, .. } => {
+            v.visit_expr_without_suffix(target);
+            v.visit_expr_no_top_alt(suffix);
+        }
+        ExprWithSuffix::Except { target, tokens, .. } => {
+            v.visit_expr_without_suffix(target);
+            v.visit_expr_without_suffix(tokens);
+        }
+    }
+}
+
+/// Visits the children of [`ExprWithoutSuffix`].
+pub fn walk_expr_without_suffix<'ast, V, I>(v: &mut V, node: &'ast ExprWithoutSuffix<I>)
+where
+    V: Visit<'ast, I> + ?Sized,
+    I: UnsynInput,
+{
+    match node {
+        ExprWithoutSuffix::Tilde(_, expr) => v.visit_expr_without_suffix(expr),
+        ExprWithoutSuffix::Call(call) => v.visit_ident(&call.body),
+        ExprWithoutSuffix::Paren(paren) => v.visit_expr(&paren.body),
+        ExprWithoutSuffix::Set(set) => {
+            for item in &set.body {
+                v.visit_set_item(item);
+            }
+        }
+        ExprWithoutSuffix::Str(lit) => v.visit_lit_str(lit),
+        ExprWithoutSuffix::Unicode(lit) => v.visit_lit_unicode(lit),
+        ExprWithoutSuffix::Path(path) => v.visit_path(path),
+    }
+}
+
+/// Visits the children of [`SetItem`].
+pub fn walk_set_item<'ast, V, I>(v: &mut V, node: &'ast SetItem<I>)
+where
+    V: Visit<'ast, I> + ?Sized,
+    I: UnsynInput,
+{
+    match node {
+        SetItem::Range(range) => v.visit_range(range),
+        SetItem::Str(lit) => v.visit_lit_str(lit),
+        SetItem::Unicode(lit) => v.visit_lit_unicode(lit),
+        SetItem::Path(path) => v.visit_path(path),
+    }
+}
+
+/// Visits the children of [`Range`].
+pub fn walk_range<'ast, V, I>(v: &mut V, node: &'ast Range<I>)
+where
+    V: Visit<'ast, I> + ?Sized,
+    I: UnsynInput,
+{
+    match node {
+        Range::Unicode(from, _, to) | Range::UnicodeInclusive(from, _, to) => {
+            v.visit_lit_unicode(from);
+            v.visit_lit_unicode(to);
+        }
+        Range::Str(from, _, to) | Range::StrInclusive(from, _, to) => {
+            v.visit_lit_str(from);
+            v.visit_lit_str(to);
+        }
+    }
+}
+
+/// Visits the children of [`Repeat`].
+pub fn walk_repeat<'ast, V, I>(v: &mut V, node: &'ast Repeat<I>)
+where
+    V: Visit<'ast, I> + ?Sized,
+    I: UnsynInput,
+{
+    match node {
+        Repeat::RangeToInclusive(_, to) | Repeat::RangeTo(_, to) => v.visit_lit_dec(to),
+        Repeat::RangeInclusive(from, _, to) => {
+            v.visit_lit_dec(from);
+            v.visit_lit_dec(to);
+        }
+        Repeat::RangeFrom(from, _, to) => {
+            v.visit_lit_dec(from);
+
+            if let Some(to) = to {
+                v.visit_lit_dec(to);
+            }
+        }
+        Repeat::Count(count) => v.visit_lit_dec(count),
+    }
+}
+
+/// Visits the children of [`Path`].
+pub fn walk_path<'ast, V, I>(v: &mut V, node: &'ast Path<I>)
+where
+    V: Visit<'ast, I> + ?Sized,
+    I: UnsynInput,
+{
+    for segment in node.segments.iter() {
+        v.visit_path_segment(segment);
+    }
+}
+
+/// Visits the children of [`PathSegment`].
+pub fn walk_path_segment<'ast, V, I>(v: &mut V, node: &'ast PathSegment<I>)
+where
+    V: Visit<'ast, I> + ?Sized,
+    I: UnsynInput,
+{
+    if let PathSegment::Ident(ident) = node {
+        v.visit_ident(ident);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use parserc::{AsStr, syntax::SyntaxInput};
+
+    use crate::input::TokenStream;
+
+    use super::*;
+
+    #[derive(Default)]
+    struct CollectIdents<'ast> {
+        idents: Vec<&'ast str>,
+    }
+
+    impl<'ast> Visit<'ast, TokenStream<'ast>> for CollectIdents<'ast> {
+        fn visit_ident(&mut self, node: &'ast Ident<TokenStream<'ast>>) {
+            self.idents.push(node.0.as_str());
+        }
+    }
+
+    #[test]
+    fn test_count_idents() {
+        let c = TokenStream::from(
+            "use a::{b, this::c};\nmod d;\nlexer E -> F followed G (H | <I>)* ['0'-'9', J]{2..=5};",
+        )
+        .parse::<Crate<_>>()
+        .unwrap();
+
+        let mut visitor = CollectIdents::default();
+
+        visitor.visit_crate(&c);
+
+        assert_eq!(
+            visitor.idents,
+            ["a", "b", "c", "d", "E", "F", "G", "H", "I", "J"]
+        );
+    }
+}
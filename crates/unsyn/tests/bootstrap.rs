@@ -29,6 +29,8 @@ fn bootstrap() {
 
         assert_eq!(token_stream.len(), 0);
 
+        assert_eq!(c.to_source(), content);
+
         for item in c.items.iter() {
             let Item::Use(syn, _) = item else {
                 continue;